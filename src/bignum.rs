@@ -0,0 +1,138 @@
+//! Unsigned integers of any size, stored in base 10^18 so that counting and
+//! splitting decimal digits is cheap.
+
+use std::fmt;
+use std::str::FromStr;
+
+const DIGITS_PER_LIMB: usize = 18;
+const BASE: u64 = 10u64.pow(DIGITS_PER_LIMB as u32);
+
+/// An unsigned integer of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    // least significant first, with no zero limbs at the end, so 0 has none
+    limbs: Vec<u64>
+}
+
+fn pow10(exponent: usize) -> u64 {
+    10u64.pow(exponent as u32)
+}
+
+impl BigUint {
+    fn from_limbs(mut limbs: Vec<u64>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// The value, if it fits in a `u64`.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs[..] {
+            [] => Some(0),
+            [low] => Some(low),
+            [low, high] => high.checked_mul(BASE)?.checked_add(low),
+            _ => None
+        }
+    }
+
+    /// Number of decimal digits, which is 1 for 0.
+    pub fn num_digits(&self) -> usize {
+        match self.limbs.last() {
+            None => 1,
+            Some(&top) => (self.limbs.len() - 1) * DIGITS_PER_LIMB + top.ilog10() as usize + 1
+        }
+    }
+
+    /// The number cut after its lowest `digits` decimal digits, as the number
+    /// above the cut and the number below it.
+    pub fn split_digits(&self, digits: usize) -> (BigUint, BigUint) {
+        let (whole, part) = (digits / DIGITS_PER_LIMB, digits % DIGITS_PER_LIMB);
+        if whole >= self.limbs.len() {
+            return (BigUint::default(), self.clone());
+        }
+        let mut low = self.limbs[..whole].to_vec();
+        if part > 0 {
+            low.push(self.limbs[whole] % pow10(part));
+        }
+        let high = (whole..self.limbs.len())
+            .map(|idx| {
+                let above = self.limbs.get(idx + 1).map_or(0, |&limb| limb % pow10(part));
+                self.limbs[idx] / pow10(part) + above * pow10(DIGITS_PER_LIMB - part)
+            })
+            .collect();
+        (BigUint::from_limbs(high), BigUint::from_limbs(low))
+    }
+
+    pub fn add_small(&self, term: u64) -> BigUint {
+        let mut limbs = self.limbs.clone();
+        let mut carry = term;
+        for limb in &mut limbs {
+            if carry == 0 {
+                break;
+            }
+            let sum = *limb as u128 + carry as u128;
+            *limb = (sum % BASE as u128) as u64;
+            carry = (sum / BASE as u128) as u64;
+        }
+        while carry > 0 {
+            limbs.push(carry % BASE);
+            carry /= BASE;
+        }
+        BigUint::from_limbs(limbs)
+    }
+
+    pub fn mul_small(&self, factor: u64) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 2);
+        let mut carry = 0u128;
+        for &limb in &self.limbs {
+            let product = limb as u128 * factor as u128 + carry;
+            limbs.push((product % BASE as u128) as u64);
+            carry = product / BASE as u128;
+        }
+        while carry > 0 {
+            limbs.push((carry % BASE as u128) as u64);
+            carry /= BASE as u128;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> BigUint {
+        BigUint::from_limbs(vec![value % BASE, value / BASE])
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<BigUint, String> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("`{}` is not a number", s));
+        }
+        // limbs are cut from the end, where the least significant digits are
+        let limbs = s.as_bytes()
+            .rchunks(DIGITS_PER_LIMB)
+            .map(|chunk| chunk.iter().fold(0, |limb, &digit| limb * 10 + (digit - b'0') as u64))
+            .collect();
+        Ok(BigUint::from_limbs(limbs))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((top, rest)) = self.limbs.split_last() else {
+            return f.pad("0");
+        };
+        let mut digits = top.to_string();
+        for limb in rest.iter().rev() {
+            digits.push_str(&format!("{:0width$}", limb, width = DIGITS_PER_LIMB));
+        }
+        f.pad(&digits)
+    }
+}
@@ -0,0 +1,137 @@
+use aoc_2024::days::{self, Day};
+use aoc_2024::input;
+use aoc_2024::params::Params;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+usage: aoc list
+       aoc run <day|all> [--part <1|2>] [--input <path|->] [--param <key=value>]... [--explain]
+
+Without --input, each day reads inputs/dayN/input.txt; `--input -` reads stdin.
+--param passes a setting to the day, like `--param size=7` for day 18.
+--explain shows how each answer comes about, for days that can tell.";
+
+enum Command {
+    List,
+    Run {
+        days: Vec<&'static Day>,
+        part: Option<u32>,
+        input: Option<String>,
+        params: Params,
+        explain: bool
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("list") => Ok(Command::List),
+        Some("run") => {
+            let days = match args.next().as_deref() {
+                Some("all") => days::DAYS.iter().collect(),
+                Some(day) => {
+                    let number = day.parse::<u32>()
+                        .map_err(|_| format!("invalid day: {}", day))?;
+                    vec![days::get(number).ok_or_else(|| format!("day {} is not solved", number))?]
+                },
+                None => return Err("missing day".to_string())
+            };
+            let mut part = None;
+            let mut input = None;
+            let mut params = Params::new();
+            let mut explain = false;
+            while let Some(flag) = args.next() {
+                if flag == "--explain" {
+                    explain = true;
+                    continue;
+                }
+                let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+                match flag.as_str() {
+                    "--part" => part = Some(match value.as_str() {
+                        "1" => 1,
+                        "2" => 2,
+                        _ => return Err(format!("invalid part: {}", value))
+                    }),
+                    "--input" => input = Some(value),
+                    "--param" => {
+                        let (key, value) = value.split_once('=')
+                            .ok_or_else(|| format!("invalid parameter: {}", value))?;
+                        params.set(key, value);
+                    },
+                    _ => return Err(format!("unknown flag: {}", flag))
+                }
+            }
+            if input.is_some() && days.len() > 1 {
+                return Err("--input can only be used with a single day".to_string());
+            }
+            Ok(Command::Run { days, part, input, params, explain })
+        },
+        Some(command) => Err(format!("unknown command: {}", command)),
+        None => Err("missing command".to_string())
+    }
+}
+
+// returns the name to report errors against along with the input itself
+fn read_input(day: &Day, input: Option<&str>) -> Result<(String, String), String> {
+    let (name, result) = match input {
+        Some("-") => ("<stdin>".to_string(), input::read_stdin()),
+        Some(path) => (path.to_string(), input::read_file(path)),
+        None => {
+            let path = input::day_dir(day.number).join("input.txt");
+            (path.display().to_string(), input::read_file(&path))
+        }
+    };
+    match result {
+        Ok(text) => Ok((name, text)),
+        Err(err) => Err(format!("couldn't read {}: {}", name, err))
+    }
+}
+
+fn run_day(day: &Day, part: Option<u32>, input: Option<&str>, params: &Params, explain: bool) -> Result<(), String> {
+    let (name, input) = read_input(day, input)?;
+    let parsed = day.parse_with(&input, params).map_err(|err| format!("{}: {}", name, err))?;
+    for number in [1, 2].into_iter().filter(|&number| part.is_none_or(|part| part == number)) {
+        let answer = if number == 1 { parsed.part_1() } else { parsed.part_2() };
+        let answer = answer.map_err(|err| format!("day {} part {}: {}", day.number, number, err))?;
+        println!("Part {}: {}", number, answer);
+        if explain {
+            let explanation = parsed.explain(number)
+                .map_err(|err| format!("day {} part {}: {}", day.number, number, err))?;
+            if let Some(text) = explanation {
+                println!("{}", text.trim_end());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match command {
+        Command::List => {
+            for day in &days::DAYS {
+                println!("day {}", day.number);
+            }
+            ExitCode::SUCCESS
+        },
+        Command::Run { days, part, input, params, explain } => {
+            let mut status = ExitCode::SUCCESS;
+            let show_headers = days.len() > 1;
+            for day in days {
+                if show_headers {
+                    println!("Day {}", day.number);
+                }
+                if let Err(err) = run_day(day, part, input.as_deref(), &params, explain) {
+                    eprintln!("error: {}", err);
+                    status = ExitCode::FAILURE;
+                }
+            }
+            status
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use aoc_2024::days::{self, Day};
+use aoc_2024::input;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "\
+usage: bench <day|all> [--runs <n>] [--history <path>]
+
+Times parsing and both parts of each day on inputs/dayN/input.txt, then appends
+the results to the history file (bench_history.csv by default).";
+
+const HISTORY_HEADER: &str = "timestamp,day,stage,runs,min_ns,median_ns,p99_ns";
+
+struct Options {
+    days: Vec<&'static Day>,
+    runs: usize,
+    history: PathBuf
+}
+
+struct Stats {
+    min: Duration,
+    median: Duration,
+    p99: Duration
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        // nearest-rank percentile
+        let p99_rank = (samples.len() * 99).div_ceil(100);
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p99: samples[p99_rank - 1]
+        }
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let days = match args.next().as_deref() {
+        Some("all") => days::DAYS.iter().collect(),
+        Some(day) => {
+            let number = day.parse::<u32>()
+                .map_err(|_| format!("invalid day: {}", day))?;
+            vec![days::get(number).ok_or_else(|| format!("day {} is not solved", number))?]
+        },
+        None => return Err("missing day".to_string())
+    };
+    let mut runs = 10;
+    let mut history = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("bench_history.csv");
+    while let Some(flag) = args.next() {
+        let value = args.next().ok_or_else(|| format!("missing value for {}", flag))?;
+        match flag.as_str() {
+            "--runs" => runs = match value.parse::<usize>() {
+                Ok(runs) if runs > 0 => runs,
+                _ => return Err(format!("invalid number of runs: {}", value))
+            },
+            "--history" => history = PathBuf::from(value),
+            _ => return Err(format!("unknown flag: {}", flag))
+        }
+    }
+    Ok(Options { days, runs, history })
+}
+
+fn time<T, E>(runs: usize, mut f: impl FnMut() -> Result<T, E>) -> Result<Stats, E> {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::new(samples))
+}
+
+// reports each stage as soon as it's timed, so a failing part still leaves the earlier ones
+fn bench_day(day: &Day, runs: usize, mut report: impl FnMut(&str, Stats)) -> Result<(), String> {
+    let path = input::day_dir(day.number).join("input.txt");
+    let input = input::read_file(&path)
+        .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+    let stats = time(runs, || day.parse(&input))
+        .map_err(|err| format!("{}: {}", path.display(), err))?;
+    report("parse", stats);
+    let parsed = day.parse(&input)
+        .map_err(|err| format!("{}: {}", path.display(), err))?;
+    let stats = time(runs, || parsed.part_1())
+        .map_err(|err| format!("day {} part 1: {}", day.number, err))?;
+    report("part 1", stats);
+    let stats = time(runs, || parsed.part_2())
+        .map_err(|err| format!("day {} part 2: {}", day.number, err))?;
+    report("part 2", stats);
+    Ok(())
+}
+
+fn append_history(path: &PathBuf, rows: &[String]) -> std::io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{}", HISTORY_HEADER)?;
+    }
+    for row in rows {
+        writeln!(file, "{}", row)?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let mut status = ExitCode::SUCCESS;
+    let mut rows = vec![];
+    println!("{:>3}  {:<6}  {:>5}  {:>10}  {:>10}  {:>10}", "day", "stage", "runs", "min", "median", "p99");
+    for day in options.days {
+        let result = bench_day(day, options.runs, |stage, stats| {
+            println!("{:>3}  {:<6}  {:>5}  {:>10.2?}  {:>10.2?}  {:>10.2?}",
+                day.number, stage, options.runs, stats.min, stats.median, stats.p99);
+            rows.push(format!("{},{},{},{},{},{},{}",
+                timestamp, day.number, stage, options.runs,
+                stats.min.as_nanos(), stats.median.as_nanos(), stats.p99.as_nanos()));
+        });
+        if let Err(err) = result {
+            eprintln!("error: {}", err);
+            status = ExitCode::FAILURE;
+        }
+    }
+    if let Err(err) = append_history(&options.history, &rows) {
+        eprintln!("error: couldn't write {}: {}", options.history.display(), err);
+        status = ExitCode::FAILURE;
+    }
+    status
+}
@@ -36,6 +36,79 @@ overload!((a: &mut Coord) += (b: ?Coord) { a.0 += b.0; a.1 += b.1 });
 overload!((a: &mut Coord) *= (b: ?i64) { a.0 *= b; a.1 *= b });
 overload!(- (a: ?Coord) -> Coord { Coord(-a.0, -a.1) });
 
+/// Compass direction on a map drawn with `y` growing downwards, so `North`
+/// is `Coord(0, -1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+impl Direction {
+    /// All directions, clockwise from `North`.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest
+    ];
+
+    /// The four cardinal directions, clockwise from `North`.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West
+    ];
+
+    // eighths of a full turn clockwise
+    fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn is_cardinal(self) -> bool {
+        (self as usize).is_multiple_of(2)
+    }
+
+    pub fn to_coord(self) -> Coord {
+        match self {
+            Direction::North => Coord(0, -1),
+            Direction::NorthEast => Coord(1, -1),
+            Direction::East => Coord(1, 0),
+            Direction::SouthEast => Coord(1, 1),
+            Direction::South => Coord(0, 1),
+            Direction::SouthWest => Coord(-1, 1),
+            Direction::West => Coord(-1, 0),
+            Direction::NorthWest => Coord(-1, -1)
+        }
+    }
+}
+
+overload!((a: ?Coord) + (b: ?Direction) -> Coord { a + b.to_coord() });
+overload!((a: &mut Coord) += (b: ?Direction) { *a += b.to_coord() });
+
 impl From<(i64, i64)> for Coord
 {
     fn from(value: (i64, i64)) -> Coord {
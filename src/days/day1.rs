@@ -0,0 +1,43 @@
+use crate::error::{self, Error, Result};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<u32>, Vec<u32>);
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+        input.lines().filter(|line| !line.is_empty()).map(|line| {
+            let nums: Vec<u32> = line.split_whitespace()
+                .map(|s| error::parse_token::<u32>(input, s))
+                .collect::<Result<_>>()?;
+            if nums.len() != 2 {
+                return Err(Error::at(input, line, "expected two numbers"));
+            }
+            Ok((nums[0], nums[1]))
+        }).collect()
+    }
+
+    fn part_1((left, right): &(Vec<u32>, Vec<u32>)) -> Result<u32> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+        left.sort();
+        right.sort();
+        Ok(left.into_iter().zip(right).map(|(l, r)| l.abs_diff(r)).sum())
+    }
+
+    fn part_2((left, right): &(Vec<u32>, Vec<u32>)) -> Result<u32> {
+        let mut right_count: HashMap<u32, u32> = HashMap::new();
+        for &num in right {
+            right_count.entry(num).and_modify(|count| *count += 1).or_insert(1);
+        }
+        let mut similarity = 0;
+        for num in left {
+            similarity += num * right_count.get(num).unwrap_or(&0);
+        }
+        Ok(similarity)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::coord::Coord;
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use std::collections::{HashSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Height(u8);
+
+impl TryFrom<char> for Height {
+    type Error = char;
+
+    fn try_from(value: char) -> std::result::Result<Height, char> {
+        value.to_digit(10).map(|digit| Height(digit as u8)).ok_or(value)
+    }
+}
+
+pub struct TopoMap {
+    grid: Grid<Height>,
+    trailheads: Vec<Coord>
+}
+
+fn score_trailhead(grid: &Grid<Height>, head: Coord) -> u32 {
+    let mut stack = vec![head];
+    let mut visited: HashSet<Coord> = HashSet::new();
+    let mut visited_nines: HashSet<Coord> = HashSet::new();
+    while let Some(coord) = stack.pop() {
+        if !visited.insert(coord) {
+            continue;
+        }
+        let Height(height) = *grid.get(coord).unwrap();
+        if height == 9 {
+            visited_nines.insert(coord);
+            continue;
+        }
+        for (next, &next_height) in grid.neighbors4(coord) {
+            if next_height == Height(height + 1) {
+                stack.push(next);
+            }
+        }
+    }
+    visited_nines.len() as u32
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = TopoMap;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<TopoMap> {
+        let grid: Grid<Height> = Grid::parse(input)?;
+        let trailheads = grid.iter_with_coords()
+            .filter(|&(_, &height)| height == Height(0))
+            .map(|(coord, _)| coord)
+            .collect();
+        Ok(TopoMap { grid, trailheads })
+    }
+
+    fn part_1(map: &TopoMap) -> Result<u32> {
+        let mut total_score = 0;
+        for &trailhead in &map.trailheads {
+            total_score += score_trailhead(&map.grid, trailhead);
+        }
+        Ok(total_score)
+    }
+
+    fn part_2(map: &TopoMap) -> Result<u32> {
+        let TopoMap { grid, trailheads } = map;
+        let mut ways_by_height: Vec<HashMap<Coord, u32>> = vec![
+            trailheads.iter().map(|&trailhead| (trailhead, 1)).collect()
+        ];
+        for h in 1..=9 {
+            let mut ways: HashMap<Coord, u32> = HashMap::new();
+            for (&coord, n) in &ways_by_height[h as usize - 1] {
+                for (next, &height) in grid.neighbors4(coord) {
+                    if height == Height(h) {
+                        *ways.entry(next).or_insert(0) += n;
+                    }
+                }
+            }
+            ways_by_height.push(ways);
+        }
+        Ok(ways_by_height[9].values().sum())
+    }
+}
@@ -0,0 +1,264 @@
+use crate::bignum::BigUint;
+use crate::error::{self, Error, Result};
+use crate::params::Params;
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+/// How many stones of each number there are. Their order never matters, and
+/// the same numbers keep coming up, so this stays small however many stones
+/// there are.
+pub type Histogram = HashMap<BigUint, u128>;
+
+/// Which stones a rule applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    Equals(BigUint),
+    EvenDigits,
+    OddDigits,
+    Any
+}
+
+/// One of the stones a rule turns a stone `x` into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Term {
+    Literal(BigUint),
+    Same,
+    /// The upper half of the digits of `x`, with the middle digit if there's an odd number.
+    Left,
+    /// The lower half of the digits of `x`.
+    Right,
+    Times(u64),
+    Plus(u64)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub condition: Condition,
+    pub outputs: Vec<Term>
+}
+
+/// Rules for what a stone turns into when you blink, of which the first one
+/// that applies is used. They're written one per line, or separated by `;`,
+/// as a condition and the stones it turns into:
+///
+/// ```text
+/// 0 -> 1
+/// even digits -> left, right
+/// any -> x * 2024
+/// ```
+///
+/// Conditions are a number, `even digits`, `odd digits` or `any`. Stones are
+/// a number, `x`, `left`, `right`, `x * n` or `x + n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    pub rules: Vec<Rule>
+}
+
+impl Default for RuleSet {
+    /// The rules from the puzzle.
+    fn default() -> RuleSet {
+        RuleSet { rules: vec![
+            Rule { condition: Condition::Equals(BigUint::from(0)), outputs: vec![Term::Literal(BigUint::from(1))] },
+            Rule { condition: Condition::EvenDigits, outputs: vec![Term::Left, Term::Right] },
+            Rule { condition: Condition::Any, outputs: vec![Term::Times(2024)] }
+        ] }
+    }
+}
+
+fn parse_number(spec: &str, token: &str) -> Result<BigUint> {
+    token.parse().map_err(|_| Error::at(spec, token, format!("invalid number `{}`", token)))
+}
+
+fn parse_condition(spec: &str, condition: &str) -> Result<Condition> {
+    match condition.split_whitespace().collect::<Vec<&str>>()[..] {
+        ["any"] => Ok(Condition::Any),
+        ["even", "digits"] => Ok(Condition::EvenDigits),
+        ["odd", "digits"] => Ok(Condition::OddDigits),
+        [number] if number.starts_with(|c: char| c.is_ascii_digit()) => {
+            Ok(Condition::Equals(parse_number(spec, number)?))
+        },
+        _ => Err(Error::at(spec, condition, format!("unknown condition `{}`", condition)))
+    }
+}
+
+fn parse_term(spec: &str, term: &str) -> Result<Term> {
+    let unknown = || Error::at(spec, term, format!("unknown stone `{}`", term));
+    match term {
+        "x" => Ok(Term::Same),
+        "left" => Ok(Term::Left),
+        "right" => Ok(Term::Right),
+        _ if term.starts_with(|c: char| c.is_ascii_digit()) => Ok(Term::Literal(parse_number(spec, term)?)),
+        _ => {
+            let operation = term.strip_prefix('x').ok_or_else(unknown)?.trim_start();
+            if let Some(factor) = operation.strip_prefix('*') {
+                Ok(Term::Times(error::parse_token(spec, factor.trim())?))
+            } else if let Some(addend) = operation.strip_prefix('+') {
+                Ok(Term::Plus(error::parse_token(spec, addend.trim())?))
+            } else {
+                Err(unknown())
+            }
+        }
+    }
+}
+
+impl RuleSet {
+    /// Reads rules written as described above. Anything after a `#` on a
+    /// line is ignored.
+    pub fn parse(spec: &str) -> Result<RuleSet> {
+        let mut rules = vec![];
+        // comments go first, so that a `;` inside one doesn't start a rule
+        let rule_texts = spec.lines()
+            .flat_map(|line| line.split('#').next().unwrap().split(';'))
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+        for line in rule_texts {
+            let (condition, outputs) = line.split_once("->")
+                .ok_or_else(|| Error::at(spec, line, "expected `->` between a condition and stones"))?;
+            let condition = parse_condition(spec, condition.trim())?;
+            let outputs = outputs.split(',')
+                .map(|term| parse_term(spec, term.trim()))
+                .collect::<Result<Vec<Term>>>()?;
+            rules.push(Rule { condition, outputs });
+        }
+        Ok(RuleSet { rules })
+    }
+
+    /// The stones `stone` turns into.
+    pub fn apply(&self, stone: &BigUint) -> Result<Vec<BigUint>> {
+        let num_digits = stone.num_digits();
+        let rule = self.rules.iter()
+            .find(|rule| match &rule.condition {
+                Condition::Equals(value) => value == stone,
+                Condition::EvenDigits => num_digits.is_multiple_of(2),
+                Condition::OddDigits => !num_digits.is_multiple_of(2),
+                Condition::Any => true
+            })
+            .ok_or_else(|| Error::solve(format!("no rule applies to stone {}", stone)))?;
+        let halves = || stone.split_digits(num_digits / 2);
+        Ok(rule.outputs.iter()
+            .map(|term| match term {
+                Term::Literal(value) => value.clone(),
+                Term::Same => stone.clone(),
+                Term::Left => halves().0,
+                Term::Right => halves().1,
+                Term::Times(factor) => stone.mul_small(*factor),
+                Term::Plus(addend) => stone.add_small(*addend)
+            })
+            .collect())
+    }
+
+    fn blink(&self, stones: &Histogram) -> Result<Histogram> {
+        let mut next = Histogram::with_capacity(stones.len());
+        for (stone, &count) in stones {
+            for output in self.apply(stone)? {
+                let total = next.entry(output).or_default();
+                *total = total.saturating_add(count);
+            }
+        }
+        Ok(next)
+    }
+}
+
+/// The stones after `blinks` blinks come back after `length` more.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub blinks: u64,
+    pub length: u64
+}
+
+/// Number of stones after blinking, and whether the stones started
+/// repeating on the way there, which lets any number of blinks be counted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evolution {
+    pub count: u128,
+    pub cycle: Option<Cycle>
+}
+
+/// Blinks `blinks` times. The stones are compared against the ones at every
+/// power-of-two blink (Brent's cycle detection), so if they ever repeat,
+/// the blinks left over are cut down to less than a single cycle.
+pub fn evolve(stones: &Histogram, rules: &RuleSet, mut blinks: u64) -> Result<Evolution> {
+    let mut stones = stones.clone();
+    let mut checkpoint = (stones.clone(), 0);
+    let mut cycle = None;
+    let mut blink = 0;
+    while blink < blinks {
+        stones = rules.blink(&stones)?;
+        blink += 1;
+        if cycle.is_some() {
+            continue;
+        }
+        let (seen, seen_at) = &checkpoint;
+        if stones == *seen {
+            let length = blink - seen_at;
+            cycle = Some(Cycle { blinks: *seen_at, length });
+            blinks = blink + (blinks - blink) % length;
+        } else if blink == 2 * seen_at || *seen_at == 0 {
+            checkpoint = (stones.clone(), blink);
+        }
+    }
+    let count = stones.values().fold(0, |total: u128, &count| total.saturating_add(count));
+    Ok(Evolution { count, cycle })
+}
+
+/// The stones, the rules for blinking at them, and how many times each
+/// part blinks. Counts saturate at `u128::MAX` rather than overflow.
+pub struct Puzzle {
+    stones: Histogram,
+    rules: RuleSet,
+    blinks_1: u64,
+    blinks_2: u64
+}
+
+impl Puzzle {
+    fn evolve(&self, part: u32) -> Result<Evolution> {
+        let blinks = if part == 1 { self.blinks_1 } else { self.blinks_2 };
+        evolve(&self.stones, &self.rules, blinks)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Puzzle;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    const PARAMS: &'static [&'static str] = &["rules", "blinks_1", "blinks_2"];
+
+    fn parse(input: &str) -> Result<Puzzle> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Puzzle> {
+        let mut stones = Histogram::new();
+        for token in input.split_whitespace() {
+            *stones.entry(parse_number(input, token)?).or_default() += 1;
+        }
+        let rules = match params.get_optional::<String>("rules")? {
+            Some(spec) => RuleSet::parse(&spec).map_err(|err| Error::Param(format!("invalid `rules`: {}", err)))?,
+            None => RuleSet::default()
+        };
+        let blinks_1 = params.get("blinks_1", 25)?;
+        let blinks_2 = params.get("blinks_2", 75)?;
+        Ok(Puzzle { stones, rules, blinks_1, blinks_2 })
+    }
+
+    fn part_1(puzzle: &Puzzle) -> Result<u128> {
+        Ok(puzzle.evolve(1)?.count)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> Result<u128> {
+        Ok(puzzle.evolve(2)?.count)
+    }
+
+    fn explain(puzzle: &Puzzle, part: u32, _params: &Params) -> Result<Option<String>> {
+        let report = match puzzle.evolve(part)?.cycle {
+            Some(Cycle { blinks, length }) => {
+                format!("the stones after {} blinks repeat every {} blinks", blinks, length)
+            },
+            None => "the stones never repeat".to_string()
+        };
+        Ok(Some(report))
+    }
+}
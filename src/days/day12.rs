@@ -0,0 +1,104 @@
+use crate::coord::{Coord, Direction};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solution::Solution;
+use std::collections::{BTreeSet, BTreeMap};
+
+pub struct Garden {
+    grid: Grid<char>
+}
+
+fn perpendicular_dirs(dir: usize) -> [usize; 2] {
+    [(dir + 1) % 4, (dir + 3) % 4]
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Garden;
+    type Part1 = u32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Garden> {
+        Ok(Garden { grid: Grid::parse(input)? })
+    }
+
+    fn part_1(garden: &Garden) -> Result<u32> {
+        let grid = &garden.grid;
+        let mut unvisited: BTreeSet<Coord> = grid.iter_with_coords()
+            .map(|(coord, _)| coord)
+            .collect();
+
+        let mut price = 0;
+
+        while let Some(coord) = unvisited.pop_first() {
+            let plant_type = grid.get(coord).copied();
+            let mut stack: Vec<Coord> = vec![coord];
+            let mut perimeter = 0;
+            let mut area = 0;
+            while let Some(coord) = stack.pop() {
+                area += 1;
+                for direction in Direction::CARDINAL {
+                    let neighbor = coord + direction;
+                    if grid.get(neighbor).copied() == plant_type {
+                        if unvisited.remove(&neighbor) {
+                            stack.push(neighbor);
+                        }
+                    } else {
+                        perimeter += 1;
+                    }
+                }
+            }
+            price += perimeter * area;
+        }
+        Ok(price)
+    }
+
+    fn part_2(garden: &Garden) -> Result<i32> {
+        let grid = &garden.grid;
+        let mut unvisited: BTreeSet<Coord> = grid.iter_with_coords()
+            .map(|(coord, _)| coord)
+            .collect();
+
+        let mut price = 0;
+
+        while let Some(coord) = unvisited.pop_first() {
+            let plant_type = grid.get(coord).copied();
+            let mut stack: Vec<Coord> = vec![coord];
+            let mut side_count = 0;
+            let mut area = 0;
+            let mut side_map: BTreeMap<Coord, [bool; 4]> = BTreeMap::new();
+            while let Some(coord) = stack.pop() {
+                area += 1;
+                let mut sides = [false; 4];
+                let mut neighbor_side_count = [0; 4];
+                for (dir_idx, direction) in Direction::CARDINAL.into_iter().enumerate() {
+                    let neighbor = coord + direction;
+                    if grid.get(neighbor).copied() != plant_type {
+                        sides[dir_idx] = true;
+                        continue;
+                    }
+                    if unvisited.remove(&neighbor) {
+                        stack.push(neighbor);
+                        continue;
+                    }
+                    if let Some(neighbor_sides) = side_map.get(&neighbor) {
+                        for d in perpendicular_dirs(dir_idx) {
+                            if neighbor_sides[d] {
+                                neighbor_side_count[d] += 1;
+                            }
+                        }
+                    }
+                }
+                for dir_idx in 0..4 {
+                    if sides[dir_idx] {
+                        side_count += 1 - neighbor_side_count[dir_idx];
+                    }
+                }
+                side_map.insert(coord, sides);
+            }
+            price += area * side_count;
+        }
+        Ok(price)
+    }
+}
@@ -0,0 +1,214 @@
+use nom::{
+    IResult,
+    Parser,
+    error::Error as NomError,
+    bytes::complete::tag,
+    character::complete::{i64, space1, multispace1, line_ending},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, tuple}
+};
+
+use crate::error::{self, Error, Result};
+use crate::numtheory::{self, LinearSolution};
+use crate::params::Params;
+use crate::solution::Solution;
+use itertools::Itertools;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64)
+}
+
+fn parse_machine_parameter<'a>(label: &'a str, op: &'a str) -> impl Parser<&'a str, (i64, i64), NomError<&'a str>>
+{
+    preceded(
+        tuple((tag(label), tag(":"), space1)),
+        separated_pair(
+            preceded(pair(tag("X"), tag(op)), i64),
+            pair(tag(","), space1),
+            preceded(pair(tag("Y"), tag(op)), i64),
+        )
+    )
+}
+
+fn parse_machine(input: &str) -> IResult<&str, Machine> {
+    let (input, button_a) = parse_machine_parameter("Button A", "+").parse(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, button_b) = parse_machine_parameter("Button B", "+").parse(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, prize) = parse_machine_parameter("Prize", "=").parse(input)?;
+    let (input, _) = line_ending(input)?;
+    Ok((input, Machine { button_a, button_b, prize }))
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Machine>> {
+    separated_list1(multispace1, parse_machine)(input)
+}
+
+/// The machines along with the tokens each button press costs and how far
+/// part 2 moves the prizes.
+pub struct Arcade {
+    machines: Vec<Machine>,
+    a_cost: i64,
+    b_cost: i64,
+    offset: i64
+}
+
+/// How a machine's prize can be won.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Exactly one combination of presses reaches the prize.
+    Unique { presses_a: i64, presses_b: i64 },
+    /// The buttons move the claw in the same direction, so many combinations
+    /// reach the prize. This is the cheapest one.
+    Cheapest { presses_a: i64, presses_b: i64 },
+    Impossible
+}
+
+impl Outcome {
+    fn cost(&self, arcade: &Arcade) -> Option<i64> {
+        match *self {
+            Outcome::Unique { presses_a, presses_b } | Outcome::Cheapest { presses_a, presses_b } => {
+                presses_a.checked_mul(arcade.a_cost)?.checked_add(presses_b.checked_mul(arcade.b_cost)?)
+            },
+            Outcome::Impossible => Some(0)
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Unique { presses_a, presses_b } => {
+                write!(f, "one way, {} A and {} B", presses_a, presses_b)
+            },
+            Outcome::Cheapest { presses_a, presses_b } => {
+                write!(f, "many ways, the cheapest is {} A and {} B", presses_a, presses_b)
+            },
+            Outcome::Impossible => write!(f, "no way")
+        }
+    }
+}
+
+// The presses that reach `prize` form a line `(a + k * da, b + k * db)`. Only
+// part of it has no negative presses, and the cost changes linearly along it,
+// so the cheapest combination is at one end of that part.
+fn cheapest_on_line(machine: &Machine, prize: (i64, i64), arcade: &Arcade) -> Result<Option<(i64, i64)>> {
+    let too_many = || Error::solve("the number of presses doesn't fit in 64 bits");
+    let Machine { button_a, button_b, .. } = machine;
+    let (axis_a, axis_b, axis_prize) = if (button_a.0, button_b.0) != (0, 0) {
+        (button_a.0, button_b.0, prize.0)
+    } else if (button_a.1, button_b.1) != (0, 0) {
+        (button_a.1, button_b.1, prize.1)
+    } else {
+        // neither button moves the claw, and the prize is where it starts
+        return Ok(Some((0, 0)));
+    };
+    // the equations have solutions, so the only way to find none is overflow
+    let ((a, b), (da, db)) = numtheory::solve_diophantine(axis_a, axis_b, axis_prize).ok_or_else(too_many)?;
+    // the range of k where neither button is pressed a negative number of times
+    let (mut k_min, mut k_max): (Option<i64>, Option<i64>) = (None, None);
+    for (presses, step) in [(a, da), (b, db)] {
+        if step > 0 {
+            let bound = numtheory::div_ceil(presses.checked_neg().ok_or_else(too_many)?, step);
+            k_min = Some(k_min.map_or(bound, |k| k.max(bound)));
+        } else if step < 0 {
+            let bound = numtheory::div_floor(presses, -step);
+            k_max = Some(k_max.map_or(bound, |k| k.min(bound)));
+        } else if presses < 0 {
+            return Ok(None);
+        }
+    }
+    if k_min.zip(k_max).is_some_and(|(k_min, k_max)| k_min > k_max) {
+        return Ok(None);
+    }
+    // costs aren't negative, so the end that makes the cost go down is bounded
+    let slope = da as i128 * arcade.a_cost as i128 + db as i128 * arcade.b_cost as i128;
+    let Some(k) = (if slope > 0 { k_min } else if slope < 0 { k_max } else { k_min.or(k_max).or(Some(0)) }) else {
+        return Ok(None);
+    };
+    let presses = |start: i64, step: i64| i64::try_from(start as i128 + k as i128 * step as i128).ok();
+    Ok(Some((presses(a, da).ok_or_else(too_many)?, presses(b, db).ok_or_else(too_many)?)))
+}
+
+fn outcome(machine: &Machine, offset: i64, arcade: &Arcade) -> Result<Outcome> {
+    let Machine { button_a, button_b, prize } = machine;
+    let prize = prize.0.checked_add(offset).zip(prize.1.checked_add(offset))
+        .ok_or_else(|| Error::Param(format!("`offset` {} moves the prize too far", offset)))?;
+    let equations = [[button_a.0, button_b.0], [button_a.1, button_b.1]];
+    let solution = numtheory::solve_2x2(equations, [prize.0, prize.1])
+        .ok_or_else(|| Error::solve("the number of presses doesn't fit in 64 bits"))?;
+    let outcome = match solution {
+        LinearSolution::Unique(presses_a, presses_b) if presses_a >= 0 && presses_b >= 0 => {
+            Outcome::Unique { presses_a, presses_b }
+        },
+        LinearSolution::Unique(..) | LinearSolution::NoSolution => Outcome::Impossible,
+        LinearSolution::Infinite => match cheapest_on_line(machine, prize, arcade)? {
+            Some((presses_a, presses_b)) => Outcome::Cheapest { presses_a, presses_b },
+            None => Outcome::Impossible
+        }
+    };
+    Ok(outcome)
+}
+
+fn outcomes(arcade: &Arcade, offset: i64) -> Result<Vec<Outcome>> {
+    arcade.machines.iter().enumerate()
+        .map(|(idx, machine)| outcome(machine, offset, arcade)
+            .map_err(|err| match err {
+                Error::Solve(message) => Error::solve(format!("machine {}: {}", idx + 1, message)),
+                other => other
+            }))
+        .collect()
+}
+
+// total tokens needed to win every prize that can be won
+fn tokens_required(arcade: &Arcade, offset: i64) -> Result<i64> {
+    outcomes(arcade, offset)?.iter()
+        .try_fold(0i64, |total, outcome| total.checked_add(outcome.cost(arcade)?))
+        .ok_or_else(|| Error::solve("the number of tokens overflows"))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Arcade;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    const PARAMS: &'static [&'static str] = &["a_cost", "b_cost", "offset"];
+
+    fn parse(input: &str) -> Result<Arcade> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Arcade> {
+        let machines = error::finish(input, parse_input(input))?;
+        let a_cost = params.get("a_cost", 3)?;
+        let b_cost = params.get("b_cost", 1)?;
+        if a_cost < 0 || b_cost < 0 {
+            return Err(Error::Param("`a_cost` and `b_cost` can't be negative".to_string()));
+        }
+        let offset = params.get("offset", 10000000000000)?;
+        Ok(Arcade { machines, a_cost, b_cost, offset })
+    }
+
+    fn part_1(arcade: &Arcade) -> Result<i64> {
+        tokens_required(arcade, 0)
+    }
+
+    fn part_2(arcade: &Arcade) -> Result<i64> {
+        tokens_required(arcade, arcade.offset)
+    }
+
+    // how each machine can be won
+    fn explain(arcade: &Arcade, part: u32, _params: &Params) -> Result<Option<String>> {
+        let offset = if part == 1 { 0 } else { arcade.offset };
+        let lines = outcomes(arcade, offset)?.iter().enumerate()
+            .map(|(idx, outcome)| format!("machine {}: {}", idx + 1, outcome))
+            .join("\n");
+        Ok(Some(lines))
+    }
+}
@@ -0,0 +1,122 @@
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::{space1, i64, line_ending},
+    multi::separated_list1,
+    sequence::{separated_pair, preceded}
+};
+
+use crate::coord::Coord;
+use crate::error::{self, Error, Result};
+use crate::grid::Grid;
+use crate::modular::Torus;
+use crate::numtheory;
+use crate::params::Params;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+pub struct Robot {
+    position: [i64; 2],
+    velocity: [i64; 2]
+}
+
+fn parse_robot(input: &str) -> IResult<&str, Robot> {
+    let vector = |label: &'static str| preceded(tag(label), separated_pair(i64, tag(","), i64));
+    let (input, ((px, py), (vx, vy))) = separated_pair(vector("p="), space1, vector("v="))(input)?;
+    Ok((input, Robot { position: [px, py], velocity: [vx, vy] }))
+}
+
+/// The robots along with the bathroom they move around in, which is smaller
+/// in the puzzle example.
+pub struct Bathroom {
+    robots: Vec<Robot>,
+    space: Torus<2>
+}
+
+fn parse_robot_list(input: &str) -> IResult<&str, Vec<Robot>> {
+    separated_list1(line_ending, parse_robot)(input)
+}
+
+// the spread of the robots along one axis, times the number of robots
+fn spread(positions: impl Iterator<Item = i64>) -> i64 {
+    let positions: Vec<i64> = positions.collect();
+    let n = positions.len() as i64;
+    let sum: i64 = positions.iter().sum();
+    let sum_squares: i64 = positions.iter().map(|p| p * p).sum();
+    sum_squares - sum * sum / n.max(1)
+}
+
+// The robots' positions along each axis repeat on their own, with a period of
+// at most the length of the axis, so the picture shows up at the second within
+// that period where the robots are bunched the closest along the axis. Returns
+// that second along with the period.
+fn tightest_time(robots: &[Robot], space: &Torus<2>, axis: usize) -> (i64, i64) {
+    let line = Torus { size: [space.size[axis]] };
+    let period = line.common_period(robots.iter().map(|robot| [robot.velocity[axis]]));
+    let positions_at = |t| robots.iter().map(move |robot| line.position_after([robot.position[axis]], [robot.velocity[axis]], t)[0]);
+    let tightest = (0..period).min_by_key(|&t| spread(positions_at(t))).unwrap_or(0);
+    (tightest, period)
+}
+
+fn draw_robots(robots: &[Robot], seconds: i64, space: &Torus<2>) -> Grid<char> {
+    let (width, height) = (space.size[0] as usize, space.size[1] as usize);
+    let mut grid = Grid::try_from_vec(vec!['.'; width * height], width).unwrap();
+    for robot in robots {
+        let [x, y] = space.position_after(robot.position, robot.velocity, seconds);
+        *grid.get_mut(Coord(x, y)).unwrap() = '#';
+    }
+    grid
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Bathroom;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    const PARAMS: &'static [&'static str] = &["width", "height"];
+
+    fn parse(input: &str) -> Result<Bathroom> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Bathroom> {
+        let robots = error::finish(input, parse_robot_list(input))?;
+        let size = [params.get("width", 101)?, params.get("height", 103)?];
+        let space = Torus::new(size)
+            .map_err(|_| Error::Param("`width` and `height` must be at least 1".to_string()))?;
+        Ok(Bathroom { robots, space })
+    }
+
+    fn part_1(bathroom: &Bathroom) -> Result<usize> {
+        let seconds = 100;
+        let mut quadrant_counts = [0; 4];
+        for robot in &bathroom.robots {
+            let new_position = bathroom.space.position_after(robot.position, robot.velocity, seconds);
+            if let Some(q) = bathroom.space.orthant(new_position) {
+                quadrant_counts[q] += 1;
+            }
+        }
+        Ok(quadrant_counts.into_iter().product())
+    }
+
+    fn part_2(bathroom: &Bathroom) -> Result<i64> {
+        let Bathroom { robots, space } = bathroom;
+        let (t_x, period_x) = tightest_time(robots, space, 0);
+        let (t_y, period_y) = tightest_time(robots, space, 1);
+        // the first second that lines up with the tightest second on both axes
+        let (seconds, _) = numtheory::crt(&[(t_x, period_x), (t_y, period_y)])
+            .ok_or_else(|| Error::solve("the robots never line up on both axes at once"))?;
+        Ok(seconds)
+    }
+
+    // part 2 draws the robots at the second it found
+    fn explain(bathroom: &Bathroom, part: u32, _params: &Params) -> Result<Option<String>> {
+        if part != 2 {
+            return Ok(None);
+        }
+        let seconds = Self::part_2(bathroom)?;
+        Ok(Some(draw_robots(&bathroom.robots, seconds, &bathroom.space).to_string()))
+    }
+}
@@ -0,0 +1,168 @@
+use crate::coord::{Coord, Direction};
+use crate::grid::Grid;
+use crate::error::{Error, Result};
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::fmt;
+
+fn move_from_char(c: char) -> Option<Direction> {
+    match c {
+        '<' => Some(Direction::West),
+        '>' => Some(Direction::East),
+        '^' => Some(Direction::North),
+        'v' => Some(Direction::South),
+        _ => None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SokobanCell {
+    Wall,
+    Boulder,
+    /// Left half of a box in the wide warehouse.
+    BoxLeft,
+    /// Right half of a box in the wide warehouse.
+    BoxRight,
+    Robot,
+    #[default]
+    Empty
+}
+
+use SokobanCell::*;
+
+pub struct Warehouse {
+    grid: Grid<SokobanCell>,
+    robot: Coord,
+    moves: Vec<Direction>
+}
+
+impl TryFrom<char> for SokobanCell {
+    type Error = char;
+
+    fn try_from(value: char) -> std::result::Result<SokobanCell, char> {
+        match value {
+            '#' => Ok(Wall),
+            'O' => Ok(Boulder),
+            '@' => Ok(Robot),
+            '.' => Ok(Empty),
+            c => Err(c)
+        }
+    }
+}
+
+impl fmt::Display for SokobanCell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let c = match self {
+            Wall => '#',
+            Boulder => 'O',
+            BoxLeft => '[',
+            BoxRight => ']',
+            Robot => '@',
+            Empty => '.'
+        };
+        write!(f, "{c}")
+    }
+}
+
+// Moves the robot unless something in the way can't be pushed. First collects
+// everything that would be pushed, as pushing a wide box up or down can push
+// several boxes, and only moves anything once all of them are free to move.
+fn apply_move(
+    direction: Direction,
+    grid: &mut Grid<SokobanCell>,
+    robot_coord: &mut Coord
+) {
+    let vertical = matches!(direction, Direction::North | Direction::South);
+    let mut pushed: Vec<Coord> = vec![*robot_coord];
+    let mut seen: HashSet<Coord> = HashSet::from([*robot_coord]);
+    let mut idx = 0;
+    while idx < pushed.len() {
+        let next = pushed[idx] + direction;
+        idx += 1;
+        let mut push = |coord: Coord| if seen.insert(coord) {
+            pushed.push(coord);
+        };
+        match grid.get(next) {
+            Some(Empty) => (),
+            Some(Boulder) => push(next),
+            Some(BoxLeft) => {
+                push(next);
+                if vertical {
+                    push(next + Direction::East);
+                }
+            },
+            Some(BoxRight) => {
+                push(next);
+                if vertical {
+                    push(next + Direction::West);
+                }
+            },
+            Some(Wall | Robot) | None => return
+        }
+    }
+    let cells: Vec<SokobanCell> = pushed.iter().map(|&coord| *grid.get(coord).unwrap()).collect();
+    for &coord in &pushed {
+        *grid.get_mut(coord).unwrap() = Empty;
+    }
+    for (&coord, cell) in pushed.iter().zip(cells) {
+        *grid.get_mut(coord + direction).unwrap() = cell;
+    }
+    *robot_coord += direction;
+}
+
+/// The map of part 2, where everything but the robot is twice as wide.
+fn widen(grid: &Grid<SokobanCell>, robot: Coord) -> (Grid<SokobanCell>, Coord) {
+    let cells = grid.cell_iter().flat_map(|&cell| match cell {
+        Boulder => [BoxLeft, BoxRight],
+        Robot => [Robot, Empty],
+        cell => [cell, cell]
+    });
+    let wide = Grid::try_from_iterable(cells, grid.width * 2).unwrap();
+    (wide, Coord(robot.0 * 2, robot.1))
+}
+
+fn run_robot(mut grid: Grid<SokobanCell>, mut robot_coord: Coord, moves: &[Direction]) -> Grid<SokobanCell> {
+    for &direction in moves {
+        apply_move(direction, &mut grid, &mut robot_coord);
+    }
+    grid
+}
+
+fn gps_coordinate(coord: Coord) -> i64 {
+    coord.0 + 100 * coord.1
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Warehouse;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Warehouse> {
+        let (grid_str, moves_str) = input.split_once("\n\n")
+            .ok_or_else(|| Error::at_offset(input, input.len(), "expected a blank line between the map and the moves"))?;
+        // the map comes first, so positions within it are positions within the input
+        let (mut grid, [robot]) = Grid::parse_with_markers(grid_str, ['@'])?;
+        *grid.get_mut(robot).unwrap() = Robot;
+        let moves: Vec<Direction> = moves_str.chars().flat_map(move_from_char).collect();
+        Ok(Warehouse { grid, robot, moves })
+    }
+
+    fn part_1(warehouse: &Warehouse) -> Result<i64> {
+        let grid = run_robot(warehouse.grid.clone(), warehouse.robot, &warehouse.moves);
+        Ok(grid.iter_with_coords()
+            .filter(|&(_, &cell)| cell == Boulder)
+            .map(|(coord, _)| gps_coordinate(coord))
+            .sum())
+    }
+
+    fn part_2(warehouse: &Warehouse) -> Result<i64> {
+        let (grid, robot) = widen(&warehouse.grid, warehouse.robot);
+        let grid = run_robot(grid, robot, &warehouse.moves);
+        Ok(grid.iter_with_coords()
+            .filter(|&(_, &cell)| cell == BoxLeft)
+            .map(|(coord, _)| gps_coordinate(coord))
+            .sum())
+    }
+}
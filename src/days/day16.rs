@@ -0,0 +1,69 @@
+use crate::coord::{Coord, Direction};
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Tile};
+use crate::search;
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+pub struct Maze {
+    start: Coord,
+    end: Coord,
+    grid: Grid<Tile>
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct ElfState {
+    location: Coord,
+    direction: Direction
+}
+
+fn successors(grid: &Grid<Tile>, state: &ElfState) -> Vec<(ElfState, u64)> {
+    let &ElfState { location, direction } = state;
+    let mut next = vec![
+        (ElfState { location, direction: direction.turn_left() }, 1000),
+        (ElfState { location, direction: direction.turn_right() }, 1000)
+    ];
+    let forward_location = location + direction;
+    if grid.get(forward_location) == Some(&Tile::Open) {
+        next.push((ElfState { location: forward_location, direction }, 1));
+    }
+    next
+}
+
+fn start_state(maze: &Maze) -> ElfState {
+    ElfState { location: maze.start, direction: Direction::East }
+}
+
+fn best_path(maze: &Maze) -> Option<u64> {
+    search::dijkstra(start_state(maze), |state| successors(&maze.grid, state), |state| state.location == maze.end)
+        .map(|found| found.cost)
+}
+
+fn best_seats(maze: &Maze) -> Option<usize> {
+    let found = search::dijkstra_all(start_state(maze), |state| successors(&maze.grid, state), |state| state.location == maze.end)?;
+    let tiles: HashSet<Coord> = found.states().into_iter().map(|state| state.location).collect();
+    Some(tiles.len())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Maze;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Maze> {
+        let (grid, [start, end]) = Grid::parse_with_markers(input, ['S', 'E'])?;
+        Ok(Maze { start, end, grid })
+    }
+
+    fn part_1(maze: &Maze) -> Result<u64> {
+        best_path(maze)
+            .ok_or_else(|| Error::solve("the end can't be reached from the start"))
+    }
+
+    fn part_2(maze: &Maze) -> Result<usize> {
+        best_seats(maze)
+            .ok_or_else(|| Error::solve("the end can't be reached from the start"))
+    }
+}
@@ -0,0 +1,204 @@
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::{u8, u64, line_ending},
+    multi::separated_list1,
+    sequence::{delimited, preceded}
+};
+use itertools::Itertools;
+use crate::error::{self, Error, Result};
+use crate::params::Params;
+use crate::solution::Solution;
+use crate::vm::{self, Instruction, Machine, Operand, Registers, Stop, TraceFormat};
+
+/// A program along with the registers it starts with. `budget` is how many
+/// instructions a run can take before it's assumed to never halt.
+#[derive(Debug)]
+pub struct Computer {
+    registers: Registers,
+    code: Vec<u8>,
+    budget: u64
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Registers, Vec<u8>)> {
+    let (input, a) = delimited(tag("Register A: "), u64, line_ending)(input)?;
+    let (input, b) = delimited(tag("Register B: "), u64, line_ending)(input)?;
+    let (input, c) = delimited(tag("Register C: "), u64, line_ending)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, code) = preceded(tag("Program: "), separated_list1(tag(","), u8))(input)?;
+    let registers = Registers { a, b, c, ip: 0 };
+    Ok((input, (registers, code)))
+}
+
+// The shape of program the quine search understands: a loop that runs every
+// instruction once per pass, outputs `outputs` values, shifts A right by
+// `shift` bits and jumps back to the start unless A is 0. B and C have to be
+// written before they're read in each pass, so that passes only depend on A.
+struct LoopShape {
+    shift: u32,
+    outputs: usize
+}
+
+// whether `instruction` reads B and C, and whether it writes them
+fn b_and_c_access(instruction: &Instruction) -> ((bool, bool), (bool, bool)) {
+    let operand = instruction.operand();
+    let combo = !matches!(instruction, Instruction::Bxl(_) | Instruction::Jnz(_) | Instruction::Bxc(_));
+    let reads = (
+        matches!(instruction, Instruction::Bxl(_) | Instruction::Bxc(_)) || (combo && operand == Operand::RegB),
+        matches!(instruction, Instruction::Bxc(_)) || (combo && operand == Operand::RegC)
+    );
+    let writes = (
+        matches!(instruction, Instruction::Bxl(_) | Instruction::Bst(_) | Instruction::Bxc(_) | Instruction::Bdv(_)),
+        matches!(instruction, Instruction::Cdv(_))
+    );
+    (reads, writes)
+}
+
+fn loop_shape(code: &[u8]) -> Result<LoopShape> {
+    let instructions = vm::decode(code)?;
+    let jumps = instructions.iter().filter(|instruction| matches!(instruction, Instruction::Jnz(_))).count();
+    if jumps != 1 || instructions.last() != Some(&Instruction::Jnz(Operand::Literal(0))) {
+        return Err(Error::solve("can't search for a quine: the program has to end with its only jump, `jnz 0`"));
+    }
+    let shifts: Vec<Operand> = instructions.iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Adv(operand) => Some(*operand),
+            _ => None
+        })
+        .collect();
+    let shift = match shifts[..] {
+        [Operand::Literal(shift @ 1..)] => shift as u32,
+        _ => return Err(Error::solve("can't search for a quine: the program has to shift A by a constant with a single `adv`"))
+    };
+    let mut written = (false, false);
+    for instruction in &instructions {
+        let (reads, writes) = b_and_c_access(instruction);
+        let unwritten = if reads.0 && !written.0 {
+            Some('B')
+        } else if reads.1 && !written.1 {
+            Some('C')
+        } else {
+            None
+        };
+        if let Some(register) = unwritten {
+            return Err(Error::solve(format!(
+                "can't search for a quine: `{}` reads {} before the pass writes it, which this search doesn't support",
+                instruction, register
+            )));
+        }
+        written = (written.0 || writes.0, written.1 || writes.1);
+    }
+    let outputs = instructions.iter().filter(|instruction| matches!(instruction, Instruction::Out(_))).count();
+    if outputs == 0 {
+        return Err(Error::solve("the program never outputs anything, so it can't output itself"));
+    }
+    if !code.len().is_multiple_of(outputs) {
+        return Err(Error::solve(format!(
+            "the program outputs {} values per pass, which can't add up to its length of {}",
+            outputs, code.len()
+        )));
+    }
+    Ok(LoopShape { shift, outputs })
+}
+
+// Values of register A that make the program output itself, smallest first.
+// Unless `all` is set, the search stops at the first one.
+struct Quines {
+    values: Vec<u64>,
+    all: bool,
+    // most passes at the end of the program that some value got right
+    deepest: usize
+}
+
+// Each pass only sees A shifted right by `shift` bits for every pass before
+// it, so the last pass depends on the top bits of A alone. A is built from
+// the top down, `shift` bits at a time, keeping the bits that make the
+// program's output match the end of the program, and checking the whole
+// output rather than just its first value.
+fn search(computer: &Computer, shape: &LoopShape, high_bits: u64, passes: usize, quines: &mut Quines) -> Result<()> {
+    let code = &computer.code;
+    for low_bits in 0..1 << shape.shift {
+        let a = high_bits << shape.shift | low_bits;
+        let registers = Registers { a, ..computer.registers };
+        if vm::run(code, registers, Some(computer.budget))? != code[code.len() - passes * shape.outputs..] {
+            continue;
+        }
+        quines.deepest = quines.deepest.max(passes);
+        if passes * shape.outputs == code.len() {
+            quines.values.push(a);
+        } else if a.leading_zeros() >= shape.shift {
+            search(computer, shape, a, passes + 1, quines)?;
+        }
+        if !quines.all && !quines.values.is_empty() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+fn find_quines(computer: &Computer, all: bool) -> Result<Vec<u64>> {
+    let shape = loop_shape(&computer.code)?;
+    let mut quines = Quines { values: vec![], all, deepest: 0 };
+    search(computer, &shape, 0, 1, &mut quines)?;
+    if quines.values.is_empty() {
+        let matched = quines.deepest * shape.outputs;
+        return Err(Error::solve(format!(
+            "no value of register A makes the program output itself: at best the last {} of its {} values come out right",
+            matched, computer.code.len()
+        )));
+    }
+    Ok(quines.values)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = Computer;
+    type Part1 = String;
+    type Part2 = u64;
+
+    const PARAMS: &'static [&'static str] = &["a", "budget", "trace"];
+
+    fn parse(input: &str) -> Result<Computer> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Computer> {
+        let (registers, code) = error::finish(input, parse_input(input))?;
+        if let Some(idx) = code.iter().position(|&byte| byte > 7) {
+            let program = input.split_once("Program: ").unwrap().1;
+            let token = program.split(',').nth(idx).unwrap();
+            return Err(Error::at(input, token, format!("`{}` is not a 3-bit number", code[idx])));
+        }
+        // lets the trace of another starting value be diffed against the input's
+        let a = params.get("a", registers.a)?;
+        let budget = params.get("budget", 100_000_000)?;
+        Ok(Computer { registers: Registers { a, ..registers }, code, budget })
+    }
+
+    fn part_1(computer: &Computer) -> Result<String> {
+        let output = vm::run(&computer.code, computer.registers, Some(computer.budget))?;
+        Ok(output.iter().join(","))
+    }
+
+    fn part_2(computer: &Computer) -> Result<u64> {
+        Ok(find_quines(computer, false)?[0])
+    }
+
+    // Part 1 shows every step the run takes, as a table or with `trace` set
+    // to `json`, one JSON object per line. Part 2 lists every value that
+    // makes the program output itself.
+    fn explain(computer: &Computer, part: u32, params: &Params) -> Result<Option<String>> {
+        if part == 2 {
+            let values = find_quines(computer, true)?;
+            return Ok(Some(format!("all {} values: {}", values.len(), values.iter().join(", "))));
+        }
+        let format: TraceFormat = params.get("trace", TraceFormat::Table)?;
+        let mut machine = Machine::new(&computer.code, computer.registers);
+        machine.start_trace();
+        if machine.run(Some(computer.budget))? == Stop::OutOfBudget {
+            return Err(Error::solve(format!("the program didn't halt within {} instructions", computer.budget)));
+        }
+        Ok(Some(format.render(machine.trace())))
+    }
+}
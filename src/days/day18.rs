@@ -0,0 +1,163 @@
+use crate::coord::Coord;
+use crate::grid::Grid;
+use crate::error::{self, Error, Result};
+use crate::params::Params;
+use crate::search;
+use crate::solution::Solution;
+use nom::{
+    IResult,
+    bytes::complete::tag,
+    character::complete::{i64, line_ending},
+    multi::separated_list1,
+    sequence::separated_pair
+};
+
+fn parse_coord(input: &str) -> IResult<&str, Coord> {
+    let (input, (x, y)) = separated_pair(i64, tag(","), i64)(input)?;
+    Ok((input, Coord(x, y)))
+}
+
+fn parse_coord_list(input: &str) -> IResult<&str, Vec<Coord>> {
+    separated_list1(line_ending, parse_coord)(input)
+}
+
+/// Where the bytes fall, along with the size of the memory space and how many
+/// bytes have fallen for part 1. Both are smaller in the puzzle example.
+pub struct Memory {
+    bytes: Vec<Coord>,
+    size: usize,
+    fallen: usize
+}
+
+fn generate_grid(obstacles: &[Coord], width: usize, height: usize) -> Grid<bool> {
+    let mut grid = Grid::new_with_default(width, height);
+    for &obstacle in obstacles {
+        if let Some(cell) = grid.get_mut(obstacle) {
+            *cell = true;
+        }
+    }
+    grid
+}
+
+fn taxicab_distance(a: Coord, b: Coord) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+fn shortest_distance(grid: &Grid<bool>, start: Coord, end: Coord) -> Option<i64> {
+    let successors = |&coord: &Coord| grid.neighbors4(coord)
+        .filter(|&(_, &corrupted)| !corrupted)
+        .map(|(neighbor, _)| (neighbor, 1))
+        .collect::<Vec<_>>();
+    search::astar(start, successors, |&coord| coord == end, |&coord| taxicab_distance(coord, end))
+        .map(|found| found.cost)
+}
+
+struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>
+}
+
+impl DisjointSet {
+    fn new(len: usize) -> DisjointSet {
+        DisjointSet { parent: (0..len).collect(), size: vec![1; len] }
+    }
+
+    fn find(&mut self, mut idx: usize) -> usize {
+        while self.parent[idx] != idx {
+            self.parent[idx] = self.parent[self.parent[idx]];
+            idx = self.parent[idx];
+        }
+        idx
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
+
+// Lets every byte fall, then takes them back out from the last one, joining
+// each freed cell with its free neighbors until the start meets the exit.
+fn first_blocking_byte(memory: &Memory) -> Option<Coord> {
+    let size = memory.size;
+    let mut first_fallen: Grid<Option<usize>> = Grid::new_with_default(size, size);
+    for (idx, &byte) in memory.bytes.iter().enumerate() {
+        if let Some(cell @ None) = first_fallen.get_mut(byte) {
+            *cell = Some(idx);
+        }
+    }
+    let index = |coord: Coord| coord.1 as usize * size + coord.0 as usize;
+    let mut sets = DisjointSet::new(size * size);
+    let free = |first_fallen: &Grid<Option<usize>>, coord: Coord| first_fallen.get(coord) == Some(&None);
+    for (coord, _) in first_fallen.iter_with_coords().filter(|&(_, cell)| cell.is_none()) {
+        for (neighbor, _) in first_fallen.neighbors4(coord).filter(|&(_, cell)| cell.is_none()) {
+            sets.union(index(coord), index(neighbor));
+        }
+    }
+    let start = 0;
+    let exit = index(Coord(size as i64 - 1, size as i64 - 1));
+    let connected = |sets: &mut DisjointSet, first_fallen: &Grid<Option<usize>>| {
+        free(first_fallen, Coord(0, 0)) && sets.find(start) == sets.find(exit)
+    };
+    if connected(&mut sets, &first_fallen) {
+        return None;
+    }
+    for (idx, &byte) in memory.bytes.iter().enumerate().rev() {
+        if first_fallen.get(byte) != Some(&Some(idx)) {
+            continue;
+        }
+        *first_fallen.get_mut(byte).unwrap() = None;
+        for (neighbor, _) in first_fallen.neighbors4(byte).filter(|&(_, cell)| cell.is_none()) {
+            sets.union(index(byte), index(neighbor));
+        }
+        if connected(&mut sets, &first_fallen) {
+            return Some(byte);
+        }
+    }
+    None
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Memory;
+    type Part1 = i64;
+    type Part2 = String;
+
+    const PARAMS: &'static [&'static str] = &["size", "fallen"];
+
+    fn parse(input: &str) -> Result<Memory> {
+        Self::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Memory> {
+        let bytes = error::finish(input, parse_coord_list(input))?;
+        let size = params.get("size", 71)?;
+        let fallen = params.get("fallen", 1024)?;
+        if size == 0 {
+            return Err(Error::Param("`size` must be at least 1".to_string()));
+        }
+        Ok(Memory { bytes, size, fallen })
+    }
+
+    fn part_1(memory: &Memory) -> Result<i64> {
+        let fallen = &memory.bytes[..memory.bytes.len().min(memory.fallen)];
+        let grid = generate_grid(fallen, memory.size, memory.size);
+        let exit = Coord(memory.size as i64 - 1, memory.size as i64 - 1);
+        shortest_distance(&grid, Coord(0, 0), exit)
+            .ok_or_else(|| Error::solve("the exit can't be reached"))
+    }
+
+    fn part_2(memory: &Memory) -> Result<String> {
+        let Coord(x, y) = first_blocking_byte(memory)
+            .ok_or_else(|| Error::solve("no byte cuts the exit off"))?;
+        Ok(format!("{},{}", x, y))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::error::{Error, Result};
+use crate::params::Params;
+use crate::solution::Solution;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: HashMap<u8, usize>,
+    // whether a pattern ends at this node
+    terminal: bool
+}
+
+/// Towel patterns stored as a trie, so all the patterns a design starts with
+/// are found in a single walk down from the root.
+#[derive(Debug)]
+pub struct Towels {
+    nodes: Vec<TrieNode>
+}
+
+impl Towels {
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = P>) -> Towels {
+        let mut nodes = vec![TrieNode::default()];
+        for pattern in patterns {
+            let mut node = 0;
+            for &stripe in pattern.as_ref() {
+                node = match nodes[node].children.get(&stripe) {
+                    Some(&child) => child,
+                    None => {
+                        nodes.push(TrieNode::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(stripe, child);
+                        child
+                    }
+                };
+            }
+            nodes[node].terminal = true;
+        }
+        Towels { nodes }
+    }
+
+    /// Lengths of the patterns `design` starts with, shortest first.
+    pub fn prefix_lengths<'a>(&'a self, design: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = Some(0);
+        design.iter().enumerate().map_while(move |(idx, stripe)| {
+            node = self.nodes[node?].children.get(stripe).copied();
+            node.map(|node| (idx + 1, self.nodes[node].terminal))
+        })
+        .filter(|&(_, terminal)| terminal)
+        .map(|(len, _)| len)
+    }
+
+    // ways[i] is the number of arrangements of design[i..], capped at u64::MAX
+    fn arrangement_counts(&self, design: &[u8]) -> Vec<u64> {
+        let mut ways = vec![0; design.len() + 1];
+        ways[design.len()] = 1;
+        for i in (0..design.len()).rev() {
+            ways[i] = self.prefix_lengths(&design[i..]).fold(0, |sum: u64, len| sum.saturating_add(ways[i + len]));
+        }
+        ways
+    }
+
+    /// Number of ways to line up towels to make `design`, or `None` if there
+    /// are `u64::MAX` or more.
+    pub fn count_arrangements(&self, design: &[u8]) -> Option<u64> {
+        Some(self.arrangement_counts(design)[0]).filter(|&count| count < u64::MAX)
+    }
+
+    /// Every way to make `design`, as the towels from left to right. Dead ends
+    /// are skipped, so each arrangement takes time linear in the design.
+    pub fn arrangements<'a>(&'a self, design: &'a [u8]) -> impl Iterator<Item = Vec<&'a [u8]>> + 'a {
+        let ways = self.arrangement_counts(design);
+        // each entry is a partial arrangement and how much of the design it covers
+        let mut stack: Vec<(Vec<&[u8]>, usize)> = vec![];
+        if ways[0] > 0 {
+            stack.push((vec![], 0));
+        }
+        std::iter::from_fn(move || {
+            while let Some((towels, start)) = stack.pop() {
+                if start == design.len() {
+                    return Some(towels);
+                }
+                let lengths: Vec<usize> = self.prefix_lengths(&design[start..]).collect();
+                // pushed in reverse so the shortest towel comes out first
+                for len in lengths.into_iter().rev().filter(|&len| ways[start + len] > 0) {
+                    let mut longer = towels.clone();
+                    longer.push(&design[start..start + len]);
+                    stack.push((longer, start + len));
+                }
+            }
+            None
+        })
+    }
+
+    /// The arrangement at `index` in the order of `arrangements`, without
+    /// listing the ones before it, or `None` if there aren't that many. Any
+    /// index below `u64::MAX` works, however many arrangements there are.
+    pub fn nth_arrangement<'a>(&self, design: &'a [u8], mut index: u64) -> Option<Vec<&'a [u8]>> {
+        let ways = self.arrangement_counts(design);
+        if index >= ways[0] {
+            return None;
+        }
+        let mut towels = vec![];
+        let mut start = 0;
+        while start < design.len() {
+            for len in self.prefix_lengths(&design[start..]) {
+                if index < ways[start + len] {
+                    towels.push(&design[start..start + len]);
+                    start += len;
+                    break;
+                }
+                index -= ways[start + len];
+            }
+        }
+        Some(towels)
+    }
+}
+
+/// The towels and the designs to make from them.
+#[derive(Debug)]
+pub struct Puzzle {
+    towels: Towels,
+    designs: Vec<Vec<u8>>
+}
+
+fn show_arrangement(towels: &[&[u8]]) -> String {
+    towels.iter().map(|towel| String::from_utf8_lossy(towel)).join(",")
+}
+
+// With `list` set to `n`, the first `n` arrangements of each design are
+// listed, and with `pick` set to an index, the arrangement at that index.
+fn explain_arrangements(puzzle: &Puzzle, params: &Params) -> Result<String> {
+    let list = params.get("list", 0)?;
+    let pick = params.get_optional("pick")?;
+    let mut lines = vec![];
+    for design in &puzzle.designs {
+        let count = match puzzle.towels.count_arrangements(design) {
+            Some(1) => "1 arrangement".to_string(),
+            Some(count) => format!("{} arrangements", count),
+            None => "too many arrangements to count".to_string()
+        };
+        lines.push(format!("{}: {}", String::from_utf8_lossy(design), count));
+        for towels in puzzle.towels.arrangements(design).take(list) {
+            lines.push(format!("  {}", show_arrangement(&towels)));
+        }
+        if let Some(index) = pick {
+            let picked = puzzle.towels.nth_arrangement(design, index)
+                .map_or("none".to_string(), |towels| show_arrangement(&towels));
+            lines.push(format!("  #{}: {}", index, picked));
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Puzzle;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    const PARAMS: &'static [&'static str] = &["list", "pick"];
+
+    fn parse(input: &str) -> Result<Puzzle> {
+        let mut lines = input.lines();
+        let first_line = lines.next()
+            .ok_or_else(|| Error::at_offset(input, 0, "expected a list of towel patterns"))?;
+        let patterns: Vec<&str> = first_line.split(", ")
+            .map(|token| if token.is_empty() {
+                Err(Error::at(input, token, "empty towel pattern"))
+            } else {
+                Ok(token)
+            })
+            .collect::<Result<_>>()?;
+        let towels = Towels::new(patterns);
+        let designs = lines.filter(|line| !line.is_empty()).map(|line| line.as_bytes().to_vec()).collect();
+        Ok(Puzzle { towels, designs })
+    }
+
+    fn part_1(puzzle: &Puzzle) -> Result<usize> {
+        Ok(puzzle.designs.iter()
+            .filter(|design| puzzle.towels.count_arrangements(design) != Some(0))
+            .count())
+    }
+
+    fn part_2(puzzle: &Puzzle) -> Result<u64> {
+        let mut total: u64 = 0;
+        for design in &puzzle.designs {
+            let count = puzzle.towels.count_arrangements(design).ok_or_else(|| Error::solve(format!(
+                "design {} has too many arrangements to count", String::from_utf8_lossy(design)
+            )))?;
+            total = total.checked_add(count)
+                .ok_or_else(|| Error::solve("the total number of arrangements overflows"))?;
+        }
+        Ok(total)
+    }
+
+    fn explain(puzzle: &Puzzle, part: u32, params: &Params) -> Result<Option<String>> {
+        match part {
+            2 => explain_arrangements(puzzle, params).map(Some),
+            _ => Ok(None)
+        }
+    }
+}
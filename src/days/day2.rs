@@ -0,0 +1,56 @@
+use crate::error::{self, Result};
+use crate::solution::Solution;
+
+fn is_safe(report: &[i32]) -> bool {
+    if report.len() < 2 {
+        return true;
+    }
+    let first_diff = report[1] - report[0];
+    if first_diff.abs() < 1 || first_diff.abs() > 3 {
+        return false;
+    }
+    let diff_sign = first_diff.signum();
+    for pair in report[1..].windows(2) {
+        let diff = (pair[1] - pair[0]) * diff_sign;
+        if !(1..=3).contains(&diff) {
+            return false;
+        }
+    }
+    true
+}
+
+fn safe_with_dampener(report: &[i32]) -> bool {
+    if is_safe(report) {
+        return true;
+    }
+    for i in 0..report.len() {
+        let dampened_report: Vec<i32> = report.iter().take(i).chain(report.iter().skip(i+1)).copied().collect();
+        if is_safe(&dampened_report) {
+            return true;
+        }
+    }
+    false        
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Vec<i32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+        input.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split_whitespace().map(|token| error::parse_token::<i32>(input, token)).collect())
+            .collect()
+    }
+
+    fn part_1(reports: &Vec<Vec<i32>>) -> Result<usize> {
+        Ok(reports.iter().filter(|report| is_safe(report)).count())
+    }
+
+    fn part_2(reports: &Vec<Vec<i32>>) -> Result<usize> {
+        Ok(reports.iter().filter(|report| safe_with_dampener(report)).count())
+    }
+}
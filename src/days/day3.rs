@@ -0,0 +1,63 @@
+use crate::error::{self, Error, Result};
+use crate::solution::Solution;
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont
+}
+
+// sum of the products of the `mul` instructions, ignoring the others
+fn sum_products<'a>(instructions: impl Iterator<Item = &'a Instruction>) -> Result<u64> {
+    let mut sum: u64 = 0;
+    for instruction in instructions {
+        if let Instruction::Mul(num_1, num_2) = instruction {
+            sum = num_1.checked_mul(*num_2)
+                .and_then(|product| sum.checked_add(product))
+                .ok_or_else(|| Error::solve(format!("mul({},{}) overflows", num_1, num_2)))?;
+        }
+    }
+    Ok(sum)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
+        re.captures_iter(input).map(|caps| match &caps[0] {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            _ => Ok(Instruction::Mul(
+                error::parse_token(input, caps.get(1).unwrap().as_str())?,
+                error::parse_token(input, caps.get(2).unwrap().as_str())?
+            ))
+        }).collect()
+    }
+
+    fn part_1(instructions: &Vec<Instruction>) -> Result<u64> {
+        sum_products(instructions.iter())
+    }
+
+    fn part_2(instructions: &Vec<Instruction>) -> Result<u64> {
+        let mut enabled = true;
+        let enabled_muls = instructions.iter().filter(|instruction| match instruction {
+            Instruction::Mul(..) => enabled,
+            Instruction::Do => {
+                enabled = true;
+                false
+            },
+            Instruction::Dont => {
+                enabled = false;
+                false
+            }
+        });
+        sum_products(enabled_muls)
+    }
+}
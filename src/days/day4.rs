@@ -0,0 +1,61 @@
+use crate::coord::{Coord, Direction};
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+const XMAS: [char; 4] = ['X', 'M', 'A', 'S'];
+
+fn word_search(grid: &Grid<char>, start: Coord, direction: Direction) -> bool {
+    for (i, c) in XMAS.iter().enumerate() {
+        let char_opt = grid.get(start + direction.to_coord() * i as i64);
+        if char_opt.is_none_or(|cc| c != cc) {
+            return false;
+        }
+    }
+    true
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Grid<char>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        Grid::parse(input)
+    }
+
+    fn part_1(grid: &Grid<char>) -> Result<u32> {
+        let mut count = 0;
+        for (start, _) in grid.iter_with_coords() {
+            for direction in Direction::ALL {
+                if word_search(grid, start, direction) {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn part_2(grid: &Grid<char>) -> Result<u32> {
+        let mut count = 0;
+        let letter = |x: usize, y: usize| grid.get(Coord(x as i64, y as i64)).copied().unwrap_or(' ');
+        for y in 1..grid.height.saturating_sub(1) {
+            for x in 1..grid.width.saturating_sub(1) {
+                if letter(x, y) == 'A' {
+                    let ul = letter(x-1, y-1);
+                    let ur = letter(x+1, y-1);
+                    let dl = letter(x-1, y+1);
+                    let dr = letter(x+1, y+1);
+                    let pos_diag = (dl == 'M' && ur == 'S') || (dl == 'S' && ur == 'M');
+                    let neg_diag = (ul == 'M' && dr == 'S') || (ul == 'S' && dr == 'M');
+                    if pos_diag && neg_diag {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        Ok(count)
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::{self, Error, Result};
+use crate::solution::Solution;
+use regex::Regex;
+
+pub struct Puzzle {
+    ordering_rules: Vec<(u32, u32)>,
+    pages_to_produce: Vec<Vec<u32>>
+}
+
+fn update_in_right_order(ordering_rules: &[(u32, u32)], update: &[u32]) -> bool {
+    for (pre, post) in ordering_rules {
+        let pre_idx_opt = update.iter().position(|page| page == pre);
+        let post_idx_opt = update.iter().position(|page| page == post);
+        if let Some((pre_idx, post_idx)) = pre_idx_opt.zip(post_idx_opt) {
+            if pre_idx > post_idx {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+use std::collections::{HashSet, HashMap, VecDeque};
+
+fn topo_sort(edge_list: &[(u32, u32)]) -> Result<Vec<u32>> {
+    let mut graph: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut in_degree: HashMap<u32, usize> = HashMap::new();
+
+    for &(from, to) in edge_list {
+        graph.entry(from).or_default().push(to);
+        in_degree.entry(to).and_modify(|d| *d += 1).or_insert(1);
+        in_degree.entry(from).or_insert(0);
+    }
+
+    let mut queue: VecDeque<u32> = in_degree
+        .iter()
+        .filter_map(|(&node, &deg)| if deg == 0 { Some(node) } else { None })
+        .collect();
+
+    let mut sorted: Vec<u32> = Vec::new();
+
+    while let Some(node) = queue.pop_front() {
+        sorted.push(node);
+
+        if let Some(neighbors) = graph.get(&node) {
+            for &neighbor in neighbors {
+                if let Some(deg) = in_degree.get_mut(&neighbor) {
+                    *deg -= 1;
+                    if *deg == 0 {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    if sorted.len() == in_degree.len() {
+        Ok(sorted)
+    } else {
+        Err(Error::solve("cycle detected in ordering rules"))
+    }
+}
+
+fn reorder_pages(pages: &[u32], rules: &[(u32, u32)]) -> Result<Vec<u32>> {
+    let pages: HashSet<u32> = pages.iter().copied().collect();
+    let rules_filtered: Vec<(u32, u32)> = rules.iter()
+        .filter(|(pre, post)| pages.contains(pre) && pages.contains(post))
+        .copied()
+        .collect();
+    let pages_sorted = topo_sort(&rules_filtered)?;
+    Ok(pages_sorted.into_iter().filter(|page| pages.contains(page)).collect())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Puzzle;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Puzzle> {
+        let mut ordering_rules: Vec<(u32, u32)> = Vec::new();
+        let mut pages_to_produce: Vec<Vec<u32>> = Vec::new();
+        let lines = input.lines()
+            .filter(|line| !line.is_empty());
+        let ordering_re = Regex::new(r"^([0-9]+)\|([0-9]+)$").unwrap();
+        for line in lines {
+            if let Some(caps) = ordering_re.captures(line) {
+                let left = error::parse_token(input, caps.get(1).unwrap().as_str())?;
+                let right = error::parse_token(input, caps.get(2).unwrap().as_str())?;
+                ordering_rules.push((left, right));
+            } else {
+                let update = line.split(',').map(|token| error::parse_token(input, token)).collect::<Result<_>>()?;
+                pages_to_produce.push(update);
+            }
+        }
+        Ok(Puzzle {
+            ordering_rules,
+            pages_to_produce
+        })
+    }
+
+    fn part_1(puzzle: &Puzzle) -> Result<u32> {
+        let Puzzle { ordering_rules, pages_to_produce } = puzzle;
+        let mut result = 0;
+        for update in pages_to_produce {
+            if update_in_right_order(ordering_rules, update) {
+                result += update[update.len() / 2];
+            }
+        }
+        Ok(result)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> Result<u32> {
+        let Puzzle { ordering_rules, pages_to_produce } = puzzle;
+        pages_to_produce.iter()
+            .filter(|pages| !update_in_right_order(ordering_rules, pages))
+            .map(|pages| {
+                let reordered = reorder_pages(pages, ordering_rules)?;
+                Ok(reordered[reordered.len() / 2])
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,261 @@
+use crate::coord::{Coord, Direction};
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Tile};
+use crate::params::Params;
+use crate::solution::Solution;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// The lab and where the guard starts.
+pub struct Puzzle {
+    grid: Grid<Tile>,
+    starting_position: Coord
+}
+
+// index of a cardinal direction in `Direction::CARDINAL`
+fn heading(direction: Direction) -> usize {
+    direction as usize / 2
+}
+
+// Each cell the guard walks through besides the one she starts on, in the
+// order she first reaches them, along with where she enters it from and
+// which way she's facing then. Fails if she never leaves the map.
+fn patrol(puzzle: &Puzzle) -> Result<Vec<(Coord, Coord, Direction)>> {
+    let Puzzle { grid, starting_position, .. } = puzzle;
+    let mut position = *starting_position;
+    let mut direction = Direction::North;
+    let mut visited: HashSet<Coord> = HashSet::from([position]);
+    // every loop has a turn in it, so only turns need to be remembered
+    let mut turns: HashSet<(Coord, Direction)> = HashSet::new();
+    let mut cells = vec![];
+
+    loop {
+        let next = position + direction;
+        match grid.get(next) {
+            Some(Tile::Wall) => {
+                if !turns.insert((position, direction)) {
+                    return Err(Error::solve("the guard walks in a loop and never leaves the lab"));
+                }
+                direction = direction.turn_right();
+                continue;
+            },
+            Some(Tile::Open) => (),
+            None => break
+        }
+        if visited.insert(next) {
+            cells.push((next, position, direction));
+        }
+        position = next;
+    }
+    Ok(cells)
+}
+
+/// For every open cell and heading, where the guard stops in front of the
+/// next wall, or `None` if she walks off the map first. That lets her cross
+/// a whole stretch of the map in one step.
+struct JumpTable {
+    stops: Grid<[Option<Coord>; 4]>
+}
+
+impl JumpTable {
+    fn new(grid: &Grid<Tile>) -> JumpTable {
+        let mut stops: Grid<[Option<Coord>; 4]> = Grid::new_with_default(grid.width, grid.height);
+        for direction in Direction::CARDINAL {
+            let step = direction.to_coord();
+            // visit the cells furthest along `direction` first, so the cell
+            // ahead of each one already knows where it stops
+            let mut coords: Vec<Coord> = grid.iter_with_coords()
+                .filter(|&(_, tile)| *tile == Tile::Open)
+                .map(|(coord, _)| coord)
+                .collect();
+            coords.sort_by_key(|coord| -(coord.0 * step.0 + coord.1 * step.1));
+            for coord in coords {
+                let ahead = coord + step;
+                let stop = match grid.get(ahead) {
+                    Some(Tile::Wall) => Some(coord),
+                    Some(Tile::Open) => stops.get(ahead).unwrap()[heading(direction)],
+                    None => None
+                };
+                stops.get_mut(coord).unwrap()[heading(direction)] = stop;
+            }
+        }
+        JumpTable { stops }
+    }
+
+    // where the guard stops with an extra wall at `obstacle`, which only
+    // changes the jump if it's in between her and where she'd stop otherwise
+    fn jump(&self, position: Coord, direction: Direction, obstacle: Coord) -> Option<Coord> {
+        let stop = self.stops.get(position).unwrap()[heading(direction)];
+        let step = direction.to_coord();
+        let offset = obstacle - position;
+        let distance = offset.0 * step.0 + offset.1 * step.1;
+        let in_line = distance > 0 && offset == step * distance;
+        let blocked = in_line && stop.is_none_or(|stop| {
+            let stop_offset = stop - position;
+            distance <= stop_offset.0 * step.0 + stop_offset.1 * step.1
+        });
+        if blocked { Some(obstacle - step) } else { stop }
+    }
+
+    // whether the guard walks in circles after an obstacle is put at
+    // `obstacle`, starting from `position`. `seen` marks the turns already
+    // made with `stamp`, so it doesn't need clearing between obstacles.
+    fn loops(&self, mut position: Coord, mut direction: Direction, obstacle: Coord, seen: &mut Grid<[u32; 4]>, stamp: u32) -> bool {
+        while let Some(stop) = self.jump(position, direction, obstacle) {
+            position = stop;
+            direction = direction.turn_right();
+            let mark = &mut seen.get_mut(position).unwrap()[heading(direction)];
+            if *mark == stamp {
+                return true;
+            }
+            *mark = stamp;
+        }
+        false
+    }
+}
+
+/// The loop the guard ends up walking after an obstacle is added.
+pub struct LoopWitness {
+    obstacle: Coord,
+    /// The first position and heading on the loop the guard reaches.
+    entry: (Coord, Direction),
+    /// Number of cells the guard moves through on one way around the loop.
+    length: usize,
+    /// Headings of the stretches she walks around the loop, starting with the entry's.
+    headings: Vec<Direction>,
+    map: Option<Grid<char>>
+}
+
+impl fmt::Display for LoopWitness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (position, direction) = self.entry;
+        write!(f, "obstacle at {}: loops from {} facing {:?} after {} steps, heading ",
+            self.obstacle, position, direction, self.length)?;
+        let headings: Vec<String> = self.headings.iter().map(|heading| format!("{:?}", heading)).collect();
+        write!(f, "{}", headings.join(", "))?;
+        if let Some(map) = &self.map {
+            write!(f, "\n{}", map)?;
+        }
+        Ok(())
+    }
+}
+
+// Walks the guard one cell at a time with an obstacle at `obstacle` until
+// she's somewhere she's been before facing the same way. Only called for
+// obstacles that are known to cause a loop. With `render` set, the path she
+// takes is drawn too.
+fn witness(puzzle: &Puzzle, obstacle: Coord, render: bool) -> LoopWitness {
+    let Puzzle { grid, starting_position } = puzzle;
+    let mut position = *starting_position;
+    let mut direction = Direction::North;
+    let mut history = vec![(position, direction)];
+    let mut first_seen: HashMap<(Coord, Direction), usize> = HashMap::from([((position, direction), 0)]);
+    let start = loop {
+        let next = position + direction;
+        match grid.get(next) {
+            _ if next == obstacle => direction = direction.turn_right(),
+            Some(Tile::Wall) => direction = direction.turn_right(),
+            Some(Tile::Open) => position = next,
+            None => unreachable!("the guard left the lab, so obstacle {} doesn't cause a loop", obstacle)
+        }
+        if let Some(&start) = first_seen.get(&(position, direction)) {
+            break start;
+        }
+        first_seen.insert((position, direction), history.len());
+        history.push((position, direction));
+    };
+    let cycle = &history[start..];
+    // turning in place doesn't count as a step
+    let length = (0..cycle.len()).filter(|&idx| cycle[idx].0 != cycle[(idx + 1) % cycle.len()].0).count();
+    let mut headings: Vec<Direction> = cycle.iter().map(|&(_, direction)| direction).dedup().collect();
+    // the loop can start halfway along a stretch, which then shows up at both ends
+    if headings.len() > 1 && headings.first() == headings.last() {
+        headings.pop();
+    }
+    let map = render.then(|| draw_path(puzzle, &history, obstacle));
+    LoopWitness { obstacle, entry: cycle[0], length, headings, map }
+}
+
+// the map as drawn in the puzzle, with `|` and `-` for where the guard walks
+// up and down or across, `+` where she does both, and `O` for the obstacle
+fn draw_path(puzzle: &Puzzle, history: &[(Coord, Direction)], obstacle: Coord) -> Grid<char> {
+    let cells = puzzle.grid.cell_iter().map(|tile| if *tile == Tile::Wall { '#' } else { '.' });
+    let mut map = Grid::try_from_iterable(cells, puzzle.grid.width).unwrap();
+    for &(position, direction) in history {
+        let glyph = if matches!(direction, Direction::North | Direction::South) { '|' } else { '-' };
+        let cell = map.get_mut(position).unwrap();
+        *cell = match *cell {
+            '.' => glyph,
+            c if c == glyph => glyph,
+            _ => '+'
+        };
+    }
+    *map.get_mut(obstacle).unwrap() = 'O';
+    *map.get_mut(puzzle.starting_position).unwrap() = '^';
+    map
+}
+
+// An obstacle only matters on a cell the guard walks through, and
+// everything up to when she first reaches it stays the same, so each
+// candidate is checked from just before that. Candidates are split
+// between threads.
+fn loop_obstacles(puzzle: &Puzzle) -> Result<Vec<Coord>> {
+    let candidates = patrol(puzzle)?;
+    let jumps = JumpTable::new(&puzzle.grid);
+    let threads = std::thread::available_parallelism().map_or(1, |threads| threads.get());
+    let chunk_size = candidates.len().div_ceil(threads).max(1);
+    let obstacles = std::thread::scope(|scope| {
+        let workers: Vec<_> = candidates.chunks(chunk_size)
+            .map(|chunk| {
+                let jumps = &jumps;
+                scope.spawn(move || {
+                    let mut seen = Grid::new_with_default(puzzle.grid.width, puzzle.grid.height);
+                    chunk.iter().zip(1..)
+                        .filter(|&(&(obstacle, position, direction), stamp)| {
+                            jumps.loops(position, direction, obstacle, &mut seen, stamp)
+                        })
+                        .map(|(&(obstacle, _, _), _)| obstacle)
+                        .collect::<Vec<Coord>>()
+                })
+            })
+            .collect();
+        workers.into_iter().flat_map(|worker| worker.join().unwrap()).collect()
+    });
+    Ok(obstacles)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Puzzle;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const PARAMS: &'static [&'static str] = &["render"];
+
+    fn parse(input: &str) -> Result<Puzzle> {
+        let (grid, [starting_position]) = Grid::parse_with_markers(input, ['^'])?;
+        Ok(Puzzle { grid, starting_position })
+    }
+
+    fn part_1(puzzle: &Puzzle) -> Result<usize> {
+        Ok(patrol(puzzle)?.len() + 1)
+    }
+
+    fn part_2(puzzle: &Puzzle) -> Result<usize> {
+        Ok(loop_obstacles(puzzle)?.len())
+    }
+
+    // the loop each obstacle causes, drawn on the map with `render` set
+    fn explain(puzzle: &Puzzle, part: u32, params: &Params) -> Result<Option<String>> {
+        if part != 2 {
+            return Ok(None);
+        }
+        let render = params.get("render", false)?;
+        let witnesses = loop_obstacles(puzzle)?.into_iter()
+            .map(|obstacle| witness(puzzle, obstacle, render).to_string())
+            .join("\n");
+        Ok(Some(witnesses))
+    }
+}
@@ -0,0 +1,101 @@
+use nom::{
+    IResult,
+    bytes::complete::{tag},
+    character::complete::{space1, u64},
+    multi::{separated_list1},
+    sequence::{terminated, separated_pair},
+};
+use crate::error::{self, Error, Result};
+use crate::solution::Solution;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Equation {
+    output: u64,
+    inputs: Vec<u64>
+}
+
+fn parse_equation(input: &str) -> IResult<&str, Equation> {
+    let output = terminated(u64, tag(":"));
+    let inputs = separated_list1(space1, u64);
+    separated_pair(output, space1, inputs)(input)
+        .map(|(rest, (output, inputs))| (rest, Equation { output, inputs }))
+}
+
+// `None` stands for a value too big for a u64, which no test value can
+// match. Only multiplying by 0 brings it back.
+type Operator = dyn Fn(Option<u64>, u64) -> Option<u64>;
+
+fn is_possible(eq: &Equation, ops: &[&Operator]) -> bool {
+    let mut possibilities: HashSet<Option<u64>> = HashSet::new();
+    for (idx, &input) in eq.inputs.iter().enumerate() {
+        if idx == 0 {
+            possibilities.insert(Some(input));
+            continue;
+        }
+        let mut new_possibilities = HashSet::new();
+        for &p in &possibilities {
+            for op in ops {
+                new_possibilities.insert(op(p, input));
+            }
+        }
+        possibilities = new_possibilities;
+    }
+    possibilities.contains(&Some(eq.output))
+}
+
+fn add(x: Option<u64>, y: u64) -> Option<u64> {
+    x?.checked_add(y)
+}
+
+fn multiply(x: Option<u64>, y: u64) -> Option<u64> {
+    if y == 0 { Some(0) } else { x?.checked_mul(y) }
+}
+
+fn concat_numbers(x: Option<u64>, y: u64) -> Option<u64> {
+    // 0 is written with one digit too
+    let y_digit_count = y.checked_ilog10().unwrap_or(0) + 1;
+    x?.checked_mul(10_u64.checked_pow(y_digit_count)?)?.checked_add(y)
+}
+
+// sum of the test values of the equations that can be made true with `ops`
+fn calibration_result(equations: &[Equation], ops: &[&Operator]) -> Result<u64> {
+    let mut sum: u64 = 0;
+    for eq in equations {
+        if is_possible(eq, ops) {
+            sum = sum.checked_add(eq.output)
+                .ok_or_else(|| Error::solve("the sum of the test values overflows"))?;
+        }
+    }
+    Ok(sum)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<Equation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Equation>> {
+        input.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| error::finish(input, parse_equation(line)))
+            .collect()
+    }
+
+    fn part_1(equations: &Vec<Equation>) -> Result<u64> {
+        calibration_result(equations, &[
+            &add,
+            &multiply
+        ])
+    }
+
+    fn part_2(equations: &Vec<Equation>) -> Result<u64> {
+        calibration_result(equations, &[
+            &add,
+            &multiply,
+            &concat_numbers
+        ])
+    }
+}
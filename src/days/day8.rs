@@ -1,3 +1,6 @@
+use crate::error::Result;
+use crate::grid::Grid;
+use crate::solution::Solution;
 use std::collections::{HashSet, HashMap};
 use std::borrow::Borrow;
 
@@ -19,32 +22,7 @@ fn all_antinodes(antennas: Vec<(i32, i32)>) -> impl Iterator<Item = (i32, i32)>
         .flat_map(|(a1, a2)| antinodes(a1, a2).into_iter())
 }
 
-fn parse(input: &str) -> (HashMap<char, Vec<(i32, i32)>>, i32, i32) {
-    let mut antennas: HashMap<char, Vec<(i32, i32)>> = HashMap::new();
-    let mut rows = 0;
-    let mut cols = 0;
-    for (x, line) in input.lines().filter(|line| line.len() > 0).enumerate() {
-        rows = std::cmp::max(rows, x as i32 + 1);
-        for (y, c) in line.chars().enumerate() {
-            cols = std::cmp::max(cols, y as i32 + 1);
-            if !c.is_ascii_alphanumeric() {
-                continue;
-            }
-            antennas.entry(c).or_default().push((x as i32, y as i32));
-        }
-    }
-    (antennas, rows, cols)
-}
-
-
-fn part_1(input: &str) -> usize {
-    let (antennas, rows, cols) = parse(input);
-    let antinode_set: HashSet<(i32, i32)> = antennas.into_iter()
-        .flat_map(|(_, a)| all_antinodes(a))
-        .filter(|&(x, y)| x >= 0 && x < rows && y >= 0 && y < cols)
-        .collect();
-    antinode_set.len()
-}
+pub type Antennas = HashMap<char, Vec<(i32, i32)>>;
 
 use itertools::Itertools;
 
@@ -78,17 +56,36 @@ fn all_antinodes_pt_2(antennas: &[(i32, i32)], rows: i32, cols: i32) -> HashSet<
         .collect()
 }
 
-fn part_2(input: &str) -> usize {
-    let (antennas, rows, cols) = parse(input);
-    antennas.values()
-        .flat_map(|a_set| all_antinodes_pt_2(&a_set, rows, cols))
-        .collect::<HashSet<(i32, i32)>>()
-        .len()
-}
+pub struct Day8;
 
-fn main() {
-    let input = std::io::read_to_string(std::io::stdin()).unwrap();
+impl Solution for Day8 {
+    type Parsed = (Antennas, i32, i32);
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Part 1: {}", part_1(&input));
-    println!("Part 2: {}", part_2(&input));
+    fn parse(input: &str) -> Result<(Antennas, i32, i32)> {
+        let grid: Grid<char> = Grid::parse(input)?;
+        let mut antennas: Antennas = HashMap::new();
+        for (coord, &c) in grid.iter_with_coords() {
+            if !c.is_ascii_alphanumeric() {
+                continue;
+            }
+            antennas.entry(c).or_default().push((coord.1 as i32, coord.0 as i32));
+        }
+        Ok((antennas, grid.height as i32, grid.width as i32))
+    }
+
+    fn part_1(&(ref antennas, rows, cols): &(Antennas, i32, i32)) -> Result<usize> {
+        let antinode_set: HashSet<(i32, i32)> = antennas.values().cloned().flat_map(all_antinodes)
+            .filter(|&(x, y)| x >= 0 && x < rows && y >= 0 && y < cols)
+            .collect();
+        Ok(antinode_set.len())
+    }
+
+    fn part_2(&(ref antennas, rows, cols): &(Antennas, i32, i32)) -> Result<usize> {
+        let antinode_set: HashSet<(i32, i32)> = antennas.values()
+            .flat_map(|a_set| all_antinodes_pt_2(a_set, rows, cols))
+            .collect();
+        Ok(antinode_set.len())
+    }
 }
@@ -0,0 +1,376 @@
+use crate::error::{self, Error, Result};
+use crate::params::Params;
+use crate::solution::Solution;
+use itertools::Itertools;
+use std::fmt;
+
+/// Blocks `start..start + len` of the disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: u64,
+    pub len: u64
+}
+
+/// A stretch of consecutive blocks that are either all free or all part of
+/// the same file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub file: Option<usize>,
+    pub len: u64
+}
+
+/// The disk as runs of blocks rather than single blocks, from the first
+/// block on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskMap {
+    pub runs: Vec<Run>
+}
+
+// sum of `id * position` over the blocks of `span`
+fn span_checksum(id: usize, span: Span) -> u128 {
+    let (start, len) = (span.start as u128, span.len as u128);
+    id as u128 * (start * len + len * len.saturating_sub(1) / 2)
+}
+
+impl DiskMap {
+    /// Parses the dense format, where the lengths alternate between a file
+    /// and the free space after it and files are numbered from 0. Normally
+    /// every length is a single digit, but lengths of any size can be given
+    /// separated by commas instead.
+    pub fn parse(input: &str) -> Result<DiskMap> {
+        let input_trimmed = input.trim();
+        let lengths = if input_trimmed.contains(',') {
+            input_trimmed.split(',')
+                .map(|token| error::parse_token(input, token.trim()))
+                .collect::<Result<Vec<u64>>>()?
+        } else {
+            input_trimmed.char_indices()
+                .map(|(idx, c)| c.to_digit(10)
+                    .map(|digit| digit as u64)
+                    .ok_or_else(|| Error::at(input, &input_trimmed[idx..], format!("invalid character `{}`", c))))
+                .collect::<Result<Vec<u64>>>()?
+        };
+        let runs = lengths.into_iter().enumerate()
+            .map(|(idx, len)| Run { file: (idx % 2 == 0).then_some(idx / 2), len })
+            .collect();
+        Ok(DiskMap { runs })
+    }
+
+    /// The dense format `parse` reads. Files are numbered in the order they
+    /// appear, so only a map with each file once and in order of id comes
+    /// back the same; anything else keeps its layout but not its ids.
+    pub fn encode(&self) -> String {
+        // files go at even positions and free space at odd ones
+        let mut lengths: Vec<u64> = vec![];
+        for run in &self.runs {
+            let expecting_file = lengths.len().is_multiple_of(2);
+            match (run.file, expecting_file) {
+                (Some(_), true) | (None, false) => lengths.push(run.len),
+                (Some(_), false) => lengths.extend([0, run.len]),
+                (None, true) => match lengths.last_mut() {
+                    Some(gap) => *gap += run.len,
+                    None => lengths.extend([0, run.len])
+                }
+            }
+        }
+        if lengths.iter().all(|&len| len <= 9) {
+            lengths.iter().join("")
+        } else {
+            lengths.iter().join(",")
+        }
+    }
+
+    /// Total number of blocks, free or not.
+    pub fn len(&self) -> u64 {
+        self.runs.iter().map(|run| run.len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sum of every file block's position times its file's id.
+    pub fn checksum(&self) -> u128 {
+        let mut start = 0;
+        let mut checksum = 0;
+        for run in &self.runs {
+            if let Some(id) = run.file {
+                checksum += span_checksum(id, Span { start, len: run.len });
+            }
+            start += run.len;
+        }
+        checksum
+    }
+
+    // the file runs along with their ids, and the free runs, both in order
+    // of position
+    fn spans(&self) -> (Vec<(usize, Span)>, Vec<Span>) {
+        let mut files = vec![];
+        let mut gaps: Vec<Span> = vec![];
+        let mut start = 0;
+        for run in &self.runs {
+            let span = Span { start, len: run.len };
+            match run.file {
+                Some(id) => files.push((id, span)),
+                // free runs next to each other make a single gap
+                None => match gaps.last_mut() {
+                    Some(gap) if gap.start + gap.len == start => gap.len += run.len,
+                    _ => gaps.push(span)
+                }
+            }
+            start += run.len;
+        }
+        (files, gaps)
+    }
+
+    // the map with `pieces` of files where they are and free space around them
+    fn from_pieces(pieces: &[(usize, Span)], len: u64) -> DiskMap {
+        let mut pieces: Vec<&(usize, Span)> = pieces.iter().filter(|(_, span)| span.len > 0).collect();
+        pieces.sort_by_key(|(_, span)| span.start);
+        let mut runs = vec![];
+        let mut end = 0;
+        for &&(id, span) in &pieces {
+            if span.start > end {
+                runs.push(Run { file: None, len: span.start - end });
+            }
+            runs.push(Run { file: Some(id), len: span.len });
+            end = span.start + span.len;
+        }
+        if len > end {
+            runs.push(Run { file: None, len: len - end });
+        }
+        DiskMap { runs }
+    }
+}
+
+/// Block notation as in the puzzle, like `00...111...2`, with one character
+/// per block. Ids past 9 only show their last digit.
+impl fmt::Display for DiskMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for run in &self.runs {
+            let c = match run.file {
+                Some(id) => char::from_digit((id % 10) as u32, 10).unwrap(),
+                None => '.'
+            };
+            for _ in 0..run.len {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How files are moved into the free space on the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Blocks move one by one from the end of the disk, splitting files up.
+    Blocks,
+    /// Files move whole, each once, in order of decreasing id.
+    Files
+}
+
+/// `len` blocks of file `file` moving from `from` to `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub file: usize,
+    pub len: u64,
+    pub from: u64,
+    pub to: u64
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = if self.len == 1 { "" } else { "s" };
+        write!(f, "file {}: {} block{} from {} to {}", self.file, self.len, plural, self.from, self.to)
+    }
+}
+
+/// Compaction of a disk one move at a time, as an iterator over the moves.
+/// `disk_map` shows the disk after the moves so far. Whole runs of blocks
+/// move at once, so each move is at most the length of a file.
+pub struct Compactor {
+    strategy: Strategy,
+    // where the blocks of each file are; the first ones are the files as
+    // they started, in order of position, which the `Blocks` strategy shrinks
+    // from the end and adds the moved blocks after
+    pieces: Vec<(usize, Span)>,
+    gaps: Vec<Span>,
+    tree: GapTree,
+    // for `Blocks`, the first gap with free space left and the last file with
+    // blocks left to move; for `Files`, how many files haven't been tried yet,
+    // with `order` listing them
+    next_gap: usize,
+    next_file: Option<usize>,
+    order: Vec<usize>,
+    len: u64
+}
+
+impl Compactor {
+    /// With `Strategy::Files`, the map should have each file once and in
+    /// order of id, as `DiskMap::parse` makes it, since a file leaving a gap
+    /// behind is assumed to be right of every file still to move.
+    pub fn new(disk_map: &DiskMap, strategy: Strategy) -> Compactor {
+        let (pieces, gaps) = disk_map.spans();
+        let tree = GapTree::new(&gaps);
+        let next_file = pieces.len().checked_sub(1);
+        let mut order: Vec<usize> = (0..pieces.len()).collect();
+        order.sort_by_key(|&idx| pieces[idx].0);
+        Compactor { strategy, pieces, gaps, tree, next_gap: 0, next_file, order, len: disk_map.len() }
+    }
+
+    pub fn disk_map(&self) -> DiskMap {
+        DiskMap::from_pieces(&self.pieces, self.len)
+    }
+
+    pub fn checksum(&self) -> u128 {
+        self.pieces.iter().map(|&(id, span)| span_checksum(id, span)).sum()
+    }
+
+    // Blocks are taken from the end of the last file with any left, for as
+    // long as there's a gap left of it.
+    fn move_blocks(&mut self) -> Option<Move> {
+        loop {
+            let last = self.next_file?;
+            let (id, file) = self.pieces[last];
+            if file.len == 0 {
+                self.next_file = last.checked_sub(1);
+                continue;
+            }
+            let gap = self.gaps.get_mut(self.next_gap).filter(|gap| gap.start < file.start)?;
+            if gap.len == 0 {
+                self.next_gap += 1;
+                continue;
+            }
+            let len = gap.len.min(file.len);
+            let moved = Move { file: id, len, from: file.start + file.len - len, to: gap.start };
+            self.pieces[last].1.len -= len;
+            self.pieces.push((id, Span { start: gap.start, len }));
+            gap.start += len;
+            gap.len -= len;
+            return Some(moved);
+        }
+    }
+
+    // A file only ever moves left, and the gap it leaves behind is right of
+    // every file still to move, so that space never needs to be reused.
+    fn move_file(&mut self) -> Option<Move> {
+        while let Some(idx) = self.order.pop() {
+            let (id, file) = &mut self.pieces[idx];
+            // the gaps left of the file are the ones before this
+            let end = self.gaps.partition_point(|gap| gap.start < file.start);
+            let Some(gap_idx) = self.tree.first_fit(file.len, end).filter(|_| file.len > 0) else {
+                continue;
+            };
+            let gap = &mut self.gaps[gap_idx];
+            let moved = Move { file: *id, len: file.len, from: file.start, to: gap.start };
+            file.start = gap.start;
+            gap.start += file.len;
+            gap.len -= file.len;
+            self.tree.set(gap_idx, gap.len);
+            return Some(moved);
+        }
+        None
+    }
+}
+
+impl Iterator for Compactor {
+    type Item = Move;
+
+    fn next(&mut self) -> Option<Move> {
+        match self.strategy {
+            Strategy::Blocks => self.move_blocks(),
+            Strategy::Files => self.move_file()
+        }
+    }
+}
+
+/// Max-segment tree over the lengths of the gaps, to find the leftmost gap
+/// that's long enough in logarithmic time.
+struct GapTree {
+    // `tree[1]` is the root, and the children of `tree[i]` are `tree[2 * i]`
+    // and `tree[2 * i + 1]`; gap `i` is the leaf `tree[leaves + i]`
+    tree: Vec<u64>,
+    leaves: usize
+}
+
+impl GapTree {
+    fn new(gaps: &[Span]) -> GapTree {
+        let leaves = gaps.len().next_power_of_two();
+        let mut tree = vec![0; 2 * leaves];
+        for (idx, gap) in gaps.iter().enumerate() {
+            tree[leaves + idx] = gap.len;
+        }
+        for node in (1..leaves).rev() {
+            tree[node] = tree[2 * node].max(tree[2 * node + 1]);
+        }
+        GapTree { tree, leaves }
+    }
+
+    fn set(&mut self, idx: usize, len: u64) {
+        let mut node = self.leaves + idx;
+        self.tree[node] = len;
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = self.tree[2 * node].max(self.tree[2 * node + 1]);
+        }
+    }
+
+    /// The first gap before `end` that's at least `len` blocks long.
+    fn first_fit(&self, len: u64, end: usize) -> Option<usize> {
+        self.first_fit_in(1, 0, self.leaves, len, end)
+    }
+
+    // searches the subtree at `node`, which covers gaps `from..to`
+    fn first_fit_in(&self, node: usize, from: usize, to: usize, len: u64, end: usize) -> Option<usize> {
+        if from >= end || self.tree[node] < len {
+            return None;
+        }
+        if to - from == 1 {
+            return Some(from);
+        }
+        let mid = (from + to) / 2;
+        self.first_fit_in(2 * node, from, mid, len, end)
+            .or_else(|| self.first_fit_in(2 * node + 1, mid, to, len, end))
+    }
+}
+
+fn compact(disk_map: &DiskMap, strategy: Strategy) -> u128 {
+    let mut compactor = Compactor::new(disk_map, strategy);
+    compactor.by_ref().for_each(drop);
+    compactor.checksum()
+}
+
+// the disk before compacting and after every move
+fn compaction_steps(disk_map: &DiskMap, strategy: Strategy) -> String {
+    let mut compactor = Compactor::new(disk_map, strategy);
+    let mut steps = vec![disk_map.to_string()];
+    while let Some(moved) = compactor.next() {
+        steps.push(format!("{}\n{}", moved, compactor.disk_map()));
+    }
+    steps.join("\n")
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = DiskMap;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &str) -> Result<DiskMap> {
+        DiskMap::parse(input)
+    }
+
+    fn part_1(disk_map: &DiskMap) -> Result<u128> {
+        Ok(compact(disk_map, Strategy::Blocks))
+    }
+
+    fn part_2(disk_map: &DiskMap) -> Result<u128> {
+        Ok(compact(disk_map, Strategy::Files))
+    }
+
+    fn explain(disk_map: &DiskMap, part: u32, _params: &Params) -> Result<Option<String>> {
+        let strategy = if part == 1 { Strategy::Blocks } else { Strategy::Files };
+        Ok(Some(compaction_steps(disk_map, strategy)))
+    }
+}
@@ -0,0 +1,73 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+
+use crate::error::Result;
+use crate::params::Params;
+use crate::solution::{self, Prepared};
+
+pub struct Day {
+    pub number: u32,
+    prepare: fn(&str, &Params) -> Result<Box<dyn Prepared>>
+}
+
+impl Day {
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Prepared>> {
+        self.parse_with(input, &Params::new())
+    }
+
+    pub fn parse_with(&self, input: &str, params: &Params) -> Result<Box<dyn Prepared>> {
+        (self.prepare)(input, params)
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $solution:ty) => {
+        Day {
+            number: $number,
+            prepare: solution::prepare::<$solution>
+        }
+    };
+}
+
+pub static DAYS: [Day; 19] = [
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8),
+    day!(9, day9::Day9),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(15, day15::Day15),
+    day!(16, day16::Day16),
+    day!(17, day17::Day17),
+    day!(18, day18::Day18),
+    day!(19, day19::Day19),
+];
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input doesn't have the expected format. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String
+    },
+    /// The input is well-formed, but the puzzle has no answer for it.
+    Solve(String),
+    /// A parameter passed along with the input has an invalid value.
+    Param(String)
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Parse error pointing at the start of `fragment`, which must be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Error {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+        Error::at_offset(input, offset, message)
+    }
+
+    /// Parse error pointing at byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Error {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        Error::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into()
+        }
+    }
+
+    pub fn solve(message: impl Into<String>) -> Error {
+        Error::Solve(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse { line, column, message } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Solve(message) | Error::Param(message) => write!(f, "{}", message)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Parses a token that was sliced out of `input`, reporting its position on failure.
+pub fn parse_token<T: FromStr>(input: &str, token: &str) -> Result<T> {
+    token.parse::<T>()
+        .map_err(|_| Error::at(input, token, format!("invalid number `{}`", token)))
+}
+
+/// Converts the result of a nom parser run on `input`, rejecting anything but
+/// whitespace left over after it.
+pub fn finish<'a, T>(input: &'a str, result: nom::IResult<&'a str, T>) -> Result<T> {
+    match result {
+        Ok((rest, value)) => {
+            let rest_trimmed = rest.trim_start();
+            if rest_trimmed.is_empty() {
+                Ok(value)
+            } else {
+                Err(Error::at(input, rest_trimmed, "unexpected trailing input"))
+            }
+        },
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            let expected = err.code.description().to_lowercase();
+            Err(Error::at(input, err.input, format!("expected {}", expected)))
+        },
+        Err(nom::Err::Incomplete(_)) => Err(Error::at_offset(input, input.len(), "unexpected end of input"))
+    }
+}
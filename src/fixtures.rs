@@ -0,0 +1,117 @@
+//! Inputs with known answers, used as regression tests. Each one is an input
+//! file `inputs/dayN/<name>.txt` with a sidecar `<name>.answers` next to it:
+//!
+//! ```text
+//! # the example uses a smaller bathroom
+//! width = 11
+//! height = 7
+//! part_1 = 12
+//! ```
+//!
+//! `part_1` and `part_2` are the expected answers, and any other key is passed
+//! to the day as a parameter. A part without an answer isn't checked.
+
+use crate::days::{self, Day};
+use crate::input;
+use crate::params::Params;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub name: String,
+    pub input: PathBuf,
+    pub params: Params,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>
+}
+
+impl Fixture {
+    pub fn load(answers: &Path) -> Result<Fixture, String> {
+        let text = input::read_file(answers)
+            .map_err(|err| format!("couldn't read {}: {}", answers.display(), err))?;
+        let mut fixture = Fixture {
+            name: answers.file_stem().unwrap_or_default().to_string_lossy().into_owned(),
+            input: answers.with_extension("txt"),
+            params: Params::new(),
+            part_1: None,
+            part_2: None
+        };
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once('=')
+                .ok_or_else(|| format!("{}:{}: expected `key = value`", answers.display(), idx + 1))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "part_1" => fixture.part_1 = Some(value),
+                "part_2" => fixture.part_2 = Some(value),
+                key => fixture.params.set(key, value)
+            }
+        }
+        Ok(fixture)
+    }
+
+    /// Runs `day` on the fixture, describing every answer that doesn't match.
+    pub fn check(&self, day: &Day) -> Result<(), String> {
+        let text = input::read_file(&self.input)
+            .map_err(|err| format!("couldn't read {}: {}", self.input.display(), err))?;
+        let parsed = day.parse_with(&text, &self.params)
+            .map_err(|err| format!("{}: {}", self.input.display(), err))?;
+        let mut failures = vec![];
+        let parts = [
+            (1, &self.part_1, parsed.part_1()),
+            (2, &self.part_2, parsed.part_2())
+        ];
+        for (part, expected, actual) in parts {
+            let Some(expected) = expected else {
+                continue;
+            };
+            match actual {
+                Ok(actual) if &actual == expected => (),
+                Ok(actual) => failures.push(format!("part {}: expected {}, got {}", part, expected, actual)),
+                Err(err) => failures.push(format!("part {}: expected {}, got error: {}", part, expected, err))
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures.join("\n"))
+        }
+    }
+}
+
+/// All fixtures of day `number`, sorted by name.
+pub fn load(number: u32) -> Result<Vec<Fixture>, String> {
+    let dir = input::day_dir(number);
+    let entries = fs::read_dir(&dir)
+        .map_err(|err| format!("couldn't read {}: {}", dir.display(), err))?;
+    let mut fixtures = vec![];
+    for entry in entries {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        if path.extension().is_some_and(|ext| ext == "answers") {
+            fixtures.push(Fixture::load(&path)?);
+        }
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+/// Checks every fixture of day `number`, failing if there are none.
+pub fn check_day(number: u32) -> Result<(), String> {
+    let day = days::get(number).ok_or_else(|| format!("day {} is not solved", number))?;
+    let fixtures = load(number)?;
+    if fixtures.is_empty() {
+        return Err(format!("day {} has no fixtures", number));
+    }
+    let failures: Vec<String> = fixtures.iter()
+        .filter_map(|fixture| fixture.check(day).err().map(|err| format!("day {} {}:\n{}", number, fixture.name, err)))
+        .collect();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
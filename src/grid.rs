@@ -1,4 +1,5 @@
-use crate::coord::Coord;
+use crate::coord::{Coord, Direction};
+use crate::error::{self, Error};
 
 use std::fmt;
 
@@ -48,7 +49,7 @@ impl<T> Grid<T> {
     }
 
     pub fn try_from_vec(data: Vec<T>, width: usize) -> Result<Grid<T>, Vec<T>> {
-        if data.len() % width != 0 {
+        if !data.len().is_multiple_of(width) {
             return Err(data);
         }
         let height = data.len() / width;
@@ -96,6 +97,86 @@ impl<T> Grid<T> {
             .enumerate()
             .map(|(idx, cell)| (self.index_to_coord(idx), cell))
     }
+
+    /// The in-bounds cells next to `coord` in each of `directions`.
+    pub fn neighbors<'a>(&'a self, coord: Coord, directions: &'a [Direction]) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        directions.iter()
+            .map(move |&direction| coord + direction)
+            .filter_map(|neighbor| self.get(neighbor).map(|cell| (neighbor, cell)))
+    }
+
+    /// The in-bounds cells orthogonally adjacent to `coord`.
+    pub fn neighbors4(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        self.neighbors(coord, &Direction::CARDINAL)
+    }
+
+    /// The in-bounds cells orthogonally or diagonally adjacent to `coord`.
+    pub fn neighbors8(&self, coord: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        self.neighbors(coord, &Direction::ALL)
+    }
+}
+
+impl<T> Grid<T>
+where T: TryFrom<char>
+{
+    /// Parses a map with one character per cell, ignoring blank lines around it.
+    pub fn parse(input: &str) -> error::Result<Grid<T>> {
+        Grid::parse_cells(input, |_, c, rest| T::try_from(c)
+            .map_err(|_| Error::at(input, rest, format!("invalid character `{}`", c))))
+    }
+
+    // `parse_cell` gets the rest of the line starting at the cell, to point errors at it
+    fn parse_cells(input: &str, mut parse_cell: impl FnMut(Coord, char, &str) -> error::Result<T>) -> error::Result<Grid<T>> {
+        let is_blank = |line: &&str| line.trim().is_empty();
+        let mut lines: Vec<&str> = input.lines().skip_while(is_blank).collect();
+        while lines.last().is_some_and(is_blank) {
+            lines.pop();
+        }
+        let mut data = vec![];
+        let mut width_opt: Option<usize> = None;
+        for (y, line) in lines.iter().enumerate() {
+            let mut row_width = 0;
+            for (x, (idx, c)) in line.char_indices().enumerate() {
+                data.push(parse_cell(Coord(x as i64, y as i64), c, &line[idx..])?);
+                row_width += 1;
+            }
+            match width_opt {
+                Some(width) if width != row_width => {
+                    return Err(Error::at(input, line, format!("expected a row of width {}", width)));
+                },
+                Some(_) => (),
+                None => width_opt = Some(row_width)
+            }
+        }
+        let width = width_opt.ok_or_else(|| Error::at_offset(input, 0, "empty map"))?;
+        Ok(Grid { data, width, height: lines.len() })
+    }
+}
+
+impl<T> Grid<T>
+where T: TryFrom<char> + Default
+{
+    /// Like `parse`, but also pulls out the coordinates of `markers`, which
+    /// must each appear exactly once. Their cells are left at the default.
+    pub fn parse_with_markers<const N: usize>(input: &str, markers: [char; N]) -> error::Result<(Grid<T>, [Coord; N])> {
+        let mut found: [Option<Coord>; N] = [None; N];
+        let grid = Grid::parse_cells(input, |coord, c, rest| {
+            let Some(marker_idx) = markers.iter().position(|&marker| marker == c) else {
+                return T::try_from(c)
+                    .map_err(|_| Error::at(input, rest, format!("invalid character `{}`", c)));
+            };
+            if found[marker_idx].replace(coord).is_some() {
+                return Err(Error::at(input, rest, format!("`{}` appears more than once", c)));
+            }
+            Ok(T::default())
+        })?;
+        let mut coords = [Coord(0, 0); N];
+        for (idx, coord) in found.into_iter().enumerate() {
+            // there's nowhere in particular to point at, so the error is on the first line
+            coords[idx] = coord.ok_or_else(|| Error::at_offset(input, 0, format!("`{}` not found", markers[idx])))?;
+        }
+        Ok((grid, coords))
+    }
 }
 
 impl<T> Grid<T>
@@ -119,8 +200,38 @@ where T: fmt::Display
                 let cell_string = cell.to_string();
                 formatter.pad(&cell_string)?;
             }
-            write!(formatter, "\n")?;
+            writeln!(formatter)?;
         }
         Ok(())
     }
 }
+
+/// Cell of a maze-like map, where `.` is open and `#` is a wall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Tile {
+    #[default]
+    Open,
+    Wall
+}
+
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Tile, char> {
+        match value {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            c => Err(c)
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Open => '.',
+            Tile::Wall => '#'
+        };
+        write!(f, "{}", c)
+    }
+}
@@ -0,0 +1,44 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+// some inputs were saved from a Windows terminal, so they come as UTF-16 with a BOM and CRLF line endings
+pub fn decode(bytes: &[u8]) -> io::Result<String> {
+    let text = match bytes {
+        [0xff, 0xfe, rest @ ..] => decode_utf16(rest, u16::from_le_bytes)?,
+        [0xfe, 0xff, rest @ ..] => decode_utf16(rest, u16::from_be_bytes)?,
+        [0xef, 0xbb, 0xbf, rest @ ..] => utf8(rest)?,
+        _ => utf8(bytes)?
+    };
+    Ok(text.replace("\r\n", "\n"))
+}
+
+fn utf8(bytes: &[u8]) -> io::Result<String> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn decode_utf16(bytes: &[u8], from_bytes: fn([u8; 2]) -> u16) -> io::Result<String> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "odd number of bytes in UTF-16 input"));
+    }
+    let units = bytes.chunks_exact(2).map(|pair| from_bytes([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Directory holding the input of day `number` and any examples for it.
+pub fn day_dir(number: u32) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "inputs", &format!("day{}", number)].iter().collect()
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> io::Result<String> {
+    decode(&fs::read(path)?)
+}
+
+pub fn read_stdin() -> io::Result<String> {
+    let mut bytes = vec![];
+    io::stdin().read_to_end(&mut bytes)?;
+    decode(&bytes)
+}
@@ -1,2 +1,13 @@
+pub mod bignum;
 pub mod coord;
+pub mod days;
+pub mod error;
+pub mod fixtures;
 pub mod grid;
+pub mod input;
+pub mod modular;
+pub mod numtheory;
+pub mod params;
+pub mod search;
+pub mod solution;
+pub mod vm;
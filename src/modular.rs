@@ -0,0 +1,65 @@
+//! Bodies moving at a constant velocity through a space that wraps around at
+//! its edges, along any number of axes.
+
+use crate::error::{Error, Result};
+use crate::numtheory::{gcd, lcm};
+
+/// `(a * b) mod m` in `0..m`, going through `i128` so the product can't overflow.
+pub fn mul_mod(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// Where a body starting at `start` is on an axis of length `size` after
+/// moving by `velocity` for `steps` steps.
+pub fn position_after(start: i64, velocity: i64, steps: i64, size: i64) -> i64 {
+    (start.rem_euclid(size) + mul_mod(velocity, steps, size)).rem_euclid(size)
+}
+
+/// Space that wraps around at its edges, with `size[i]` positions along axis `i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Torus<const N: usize> {
+    pub size: [i64; N]
+}
+
+impl<const N: usize> Torus<N> {
+    /// Fails unless every length in `size` is positive.
+    pub fn new(size: [i64; N]) -> Result<Torus<N>> {
+        if size.iter().any(|&len| len <= 0) {
+            return Err(Error::Param(format!("torus sizes must be positive, not {:?}", size)));
+        }
+        Ok(Torus { size })
+    }
+
+    pub fn position_after(&self, start: [i64; N], velocity: [i64; N], steps: i64) -> [i64; N] {
+        std::array::from_fn(|axis| position_after(start[axis], velocity[axis], steps, self.size[axis]))
+    }
+
+    /// Which part of the space `position` lies in when every axis is cut in
+    /// half, as a number with bit `i` set for the upper half of axis `i`. On
+    /// axes of odd length, the middle position is in neither half, giving `None`.
+    pub fn orthant(&self, position: [i64; N]) -> Option<usize> {
+        let mut orthant = 0;
+        for (axis, (len, pos)) in self.size.into_iter().zip(position).enumerate() {
+            if len % 2 == 1 && pos == len / 2 {
+                return None;
+            }
+            if pos >= len - len / 2 {
+                orthant |= 1 << axis;
+            }
+        }
+        Some(orthant)
+    }
+
+    /// Number of steps after which a body moving by `velocity` is first back
+    /// where it started.
+    pub fn period(&self, velocity: [i64; N]) -> i64 {
+        (0..N).map(|axis| self.size[axis] / gcd(self.size[axis], velocity[axis]))
+            .fold(1, lcm)
+    }
+
+    /// Number of steps after which all the bodies are first back where they
+    /// started at the same time.
+    pub fn common_period(&self, velocities: impl IntoIterator<Item = [i64; N]>) -> i64 {
+        velocities.into_iter().map(|velocity| self.period(velocity)).fold(1, lcm)
+    }
+}
@@ -0,0 +1,142 @@
+//! Integer arithmetic: divisibility, modular inverses, the Chinese remainder
+//! theorem and small linear systems. Intermediate products use `i128`, so
+//! nothing overflows as long as the results fit in `i64`.
+
+/// Greatest common divisor, always non-negative.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    egcd(a, b).0
+}
+
+/// Least common multiple, always non-negative.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// `a / b` rounded down. `b` can't be 0.
+pub fn div_floor(a: i64, b: i64) -> i64 {
+    let (q, r) = (a / b, a % b);
+    if r != 0 && (r < 0) != (b < 0) { q - 1 } else { q }
+}
+
+/// `a / b` rounded up. `b` can't be 0.
+pub fn div_ceil(a: i64, b: i64) -> i64 {
+    let (q, r) = (a / b, a % b);
+    if r != 0 && (r < 0) == (b < 0) { q + 1 } else { q }
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` where `g` is the
+/// non-negative gcd of `a` and `b` and `a * x + b * y == g`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r_prev, mut r) = (a as i128, b as i128);
+    let (mut s_prev, mut s) = (1i128, 0i128);
+    let (mut t_prev, mut t) = (0i128, 1i128);
+    while r != 0 {
+        let q = r_prev / r;
+        (r_prev, r) = (r, r_prev - q * r);
+        (s_prev, s) = (s, s_prev - q * s);
+        (t_prev, t) = (t, t_prev - q * t);
+    }
+    if r_prev < 0 {
+        (r_prev, s_prev, t_prev) = (-r_prev, -s_prev, -t_prev);
+    }
+    (r_prev as i64, s_prev as i64, t_prev as i64)
+}
+
+/// The `x` in `0..m` with `a * x` congruent to 1 modulo `m`, if `a` and `m`
+/// are coprime. `m` must be positive.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem: given congruences `x ≡ r (mod m)` as `(r, m)`
+/// pairs with positive moduli, returns `(x, lcm)` where `x` in `0..lcm`
+/// satisfies all of them and every other solution differs from it by a
+/// multiple of `lcm`. The moduli don't need to be coprime, but then the
+/// congruences might contradict each other, giving `None`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut modulus) = (0i128, 1i128);
+    for &(r, m) in congruences {
+        let (r, m) = (r as i128, m as i128);
+        let (g, inverse, _) = egcd((modulus % m) as i64, m as i64);
+        let g = g as i128;
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+        // x + modulus * k ≡ r (mod m), so k ≡ diff / g * inverse (mod m / g)
+        let step = m / g;
+        let k = (diff / g % step * inverse as i128).rem_euclid(step);
+        x += modulus * k;
+        modulus *= step;
+        x = x.rem_euclid(modulus);
+    }
+    Some((x as i64, modulus as i64))
+}
+
+/// Integer solutions of `a * x + b * y == c`, as a solution `(x, y)` and a
+/// step `(dx, dy)` such that the solutions are exactly `(x + k * dx, y + k * dy)`
+/// for integer `k`. Unless `b` is 0, `x` is the smallest non-negative one.
+/// `a` and `b` can't both be 0. Gives `None` if there are no solutions, or if
+/// that one doesn't fit in `i64`.
+pub fn solve_diophantine(a: i64, b: i64, c: i64) -> Option<((i64, i64), (i64, i64))> {
+    let (g, x, y) = egcd(a, b);
+    if g == 0 || c % g != 0 {
+        return None;
+    }
+    let (a, b, c, g) = (a as i128, b as i128, c as i128, g as i128);
+    let (dx, dy) = (b / g, -a / g);
+    // x * (c / g) alone can be far outside i64 even when small solutions exist
+    let (x, y) = if dx == 0 {
+        (x as i128 * (c / g), y as i128 * (c / g))
+    } else {
+        let x = (x as i128 * (c / g)).rem_euclid(dx.abs());
+        (x, (c - a * x) / b)
+    };
+    let narrow = |n: i128| i64::try_from(n).ok();
+    Some(((narrow(x)?, narrow(y)?), (narrow(dx)?, narrow(dy)?)))
+}
+
+/// Integer solutions of a system of two linear equations in two unknowns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearSolution {
+    Unique(i64, i64),
+    NoSolution,
+    /// The equations are multiples of each other, so the solutions lie on a
+    /// line. `solve_diophantine` on either equation that isn't all zeros lists them.
+    Infinite
+}
+
+/// Solves `m[0][0] * x + m[0][1] * y == v[0]` and `m[1][0] * x + m[1][1] * y == v[1]`
+/// over the integers, using Cramer's rule. Gives `None` if the only solution
+/// doesn't fit in `i64`.
+pub fn solve_2x2(m: [[i64; 2]; 2], v: [i64; 2]) -> Option<LinearSolution> {
+    let [[a, b], [c, d]] = m.map(|row| row.map(i128::from));
+    let [e, f] = v.map(i128::from);
+    let det = a * d - b * c;
+    if det != 0 {
+        let x = e * d - b * f;
+        let y = a * f - e * c;
+        if x % det != 0 || y % det != 0 {
+            return Some(LinearSolution::NoSolution);
+        }
+        let (x, y) = (i64::try_from(x / det).ok()?, i64::try_from(y / det).ok()?);
+        return Some(LinearSolution::Unique(x, y));
+    }
+    // the rows are multiples of each other, so the right-hand side has to be too
+    if a * f != e * c || b * f != e * d {
+        return Some(LinearSolution::NoSolution);
+    }
+    let row = if m[0] != [0, 0] { 0 } else { 1 };
+    let solution = match m[row] {
+        [0, 0] if v == [0, 0] => LinearSolution::Infinite,
+        [0, 0] => LinearSolution::NoSolution,
+        [p, q] if v[row] % gcd(p, q) == 0 => LinearSolution::Infinite,
+        _ => LinearSolution::NoSolution
+    };
+    Some(solution)
+}
@@ -0,0 +1,41 @@
+use crate::error::{Error, Result};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Settings a day reads besides its input, such as the size of a grid that
+/// differs between the puzzle examples and the real input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Value of `key`, or `default` if it wasn't set.
+    pub fn get<T: FromStr>(&self, key: &str, default: T) -> Result<T> {
+        Ok(self.get_optional(key)?.unwrap_or(default))
+    }
+
+    /// Value of `key`, or `None` if it wasn't set.
+    pub fn get_optional<T: FromStr>(&self, key: &str) -> Result<Option<T>> {
+        self.0.get(key)
+            .map(|value| value.parse::<T>()
+                .map_err(|_| Error::Param(format!("invalid value `{}` for `{}`", value, key))))
+            .transpose()
+    }
+
+    /// Fails on the first key that isn't one of `known`, so that a misspelt
+    /// setting isn't silently replaced by its default.
+    pub fn check_keys(&self, known: &[&str]) -> Result<()> {
+        match self.0.keys().find(|key| !known.contains(&key.as_str())) {
+            None => Ok(()),
+            Some(key) if known.is_empty() => Err(Error::Param(format!("unknown parameter `{}`, this day takes none", key))),
+            Some(key) => Err(Error::Param(format!("unknown parameter `{}`, expected one of: {}", key, known.join(", "))))
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Searches over graphs given implicitly by a start state and a function
+//! listing the successors of a state.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cheapest way found from the start to a goal.
+#[derive(Debug, Clone)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// States from the start to the goal, both included.
+    pub path: Vec<S>,
+    /// For every state reached during the search, the state it was reached
+    /// from on the cheapest known way to it.
+    pub predecessors: HashMap<S, S>
+}
+
+/// Walks `predecessors` back from `end`, returning the states from the start to `end`.
+pub fn reconstruct_path<S>(predecessors: &HashMap<S, S>, end: S) -> Vec<S>
+where S: Clone + Eq + Hash
+{
+    let mut path = vec![end];
+    while let Some(prev) = predecessors.get(path.last().unwrap()) {
+        path.push(prev.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>
+{
+    let mut queue = VecDeque::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    while let Some((state, cost)) = queue.pop_front() {
+        if is_goal(&state) {
+            let path = reconstruct_path(&predecessors, state);
+            return Some(Found { cost, path, predecessors });
+        }
+        for next in successors(&state) {
+            if next != start && !predecessors.contains_key(&next) {
+                predecessors.insert(next.clone(), state.clone());
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, for successors given as `(state, cost of the step)`.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>
+{
+    astar(start, successors, is_goal, |_| C::default())
+}
+
+/// A* search. `heuristic` must never overestimate the cost left to reach a
+/// goal, or the result might not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>
+{
+    let mut queue: BinaryHeap<Queued<S, C>> = BinaryHeap::new();
+    let mut costs: HashMap<S, C> = HashMap::new();
+    let mut predecessors: HashMap<S, S> = HashMap::new();
+    costs.insert(start.clone(), C::default());
+    queue.push(Queued { priority: heuristic(&start), cost: C::default(), state: start });
+
+    while let Some(Queued { state, cost, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            let path = reconstruct_path(&predecessors, state);
+            return Some(Found { cost, path, predecessors });
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                predecessors.insert(next.clone(), state.clone());
+                queue.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+            }
+        }
+    }
+
+    None
+}
+
+/// Every cheapest way from the start to a goal.
+#[derive(Debug, Clone)]
+pub struct AllFound<S, C> {
+    pub cost: C,
+    /// Goals reached at the cheapest cost.
+    pub goals: Vec<S>,
+    /// For every state reached during the search, all the states it was
+    /// reached from on one of the cheapest known ways to it.
+    pub predecessors: HashMap<S, Vec<S>>
+}
+
+impl<S, C> AllFound<S, C>
+where S: Clone + Eq + Hash
+{
+    /// States on any of the cheapest paths, including the start and the goals.
+    pub fn states(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            for prev in self.predecessors.get(&state).into_iter().flatten() {
+                if states.insert(prev.clone()) {
+                    stack.push(prev.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// Each of the cheapest paths, as states from the start to a goal. There
+    /// can be exponentially many of them, so they're found lazily.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        // partial paths are built backwards from the goals
+        let mut stack: Vec<Vec<S>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+        std::iter::from_fn(move || {
+            while let Some(mut path) = stack.pop() {
+                match self.predecessors.get(path.last().unwrap()) {
+                    Some(prevs) if !prevs.is_empty() => {
+                        for prev in prevs {
+                            let mut longer = path.clone();
+                            longer.push(prev.clone());
+                            stack.push(longer);
+                        }
+                    },
+                    _ => {
+                        path.reverse();
+                        return Some(path);
+                    }
+                }
+            }
+            None
+        })
+    }
+}
+
+/// Like `dijkstra`, but keeps every cheapest way to each state instead of
+/// just one, and finds all the goals reachable at the cheapest cost. Steps
+/// must cost more than nothing, or the cheapest paths could go in circles.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool
+) -> Option<AllFound<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>
+{
+    let mut queue: BinaryHeap<Queued<S, C>> = BinaryHeap::new();
+    let mut costs: HashMap<S, C> = HashMap::new();
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut best: Option<(C, Vec<S>)> = None;
+    costs.insert(start.clone(), C::default());
+    queue.push(Queued { priority: C::default(), cost: C::default(), state: start });
+
+    while let Some(Queued { state, cost, .. }) = queue.pop() {
+        if costs.get(&state).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        // other goals might be just as cheap as the first one found
+        match &mut best {
+            Some((best_cost, _)) if cost > *best_cost => break,
+            Some((_, goals)) if is_goal(&state) => goals.push(state.clone()),
+            None if is_goal(&state) => best = Some((cost, vec![state.clone()])),
+            _ => ()
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match costs.get(&next) {
+                Some(&best) if next_cost > best => (),
+                Some(&best) if next_cost == best => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                },
+                _ => {
+                    costs.insert(next.clone(), next_cost);
+                    predecessors.insert(next.clone(), vec![state.clone()]);
+                    queue.push(Queued { priority: next_cost, cost: next_cost, state: next });
+                }
+            }
+        }
+    }
+
+    best.map(|(cost, goals)| AllFound { cost, goals, predecessors })
+}
+
+// ordered so that `BinaryHeap` pops the lowest priority first
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority).reverse()
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
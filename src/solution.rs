@@ -0,0 +1,65 @@
+use crate::error::Result;
+use crate::params::Params;
+use std::fmt;
+
+/// A day's puzzle, split into a parsing stage that runs once and two solving
+/// stages that borrow its result.
+pub trait Solution {
+    type Parsed;
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    /// Keys of the params `parse_with` and `explain` read. Any other key is an error.
+    const PARAMS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+
+    /// Parses the input for days whose answers depend on settings besides the
+    /// input itself. Days without any just parse the input.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Parsed> {
+        Self::parse(input)
+    }
+
+    fn part_1(parsed: &Self::Parsed) -> Result<Self::Part1>;
+    fn part_2(parsed: &Self::Parsed) -> Result<Self::Part2>;
+
+    /// Details on how the answer to `part` comes about, like a drawing or the
+    /// steps taken, for `aoc run --explain`. They're kept apart from the
+    /// answers, so settings for them go in `params` rather than the parsed
+    /// input. Days with nothing to show give `None`.
+    fn explain(_parsed: &Self::Parsed, _part: u32, _params: &Params) -> Result<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// Parsed input of some day with the day's type erased, so that days can be
+/// stored side by side in the registry.
+pub trait Prepared {
+    fn part_1(&self) -> Result<String>;
+    fn part_2(&self) -> Result<String>;
+    fn explain(&self, part: u32) -> Result<Option<String>>;
+}
+
+// the params are kept for `explain`
+struct PreparedInput<S: Solution>(S::Parsed, Params);
+
+impl<S: Solution> Prepared for PreparedInput<S> {
+    fn part_1(&self) -> Result<String> {
+        S::part_1(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self) -> Result<String> {
+        S::part_2(&self.0).map(|answer| answer.to_string())
+    }
+
+    fn explain(&self, part: u32) -> Result<Option<String>> {
+        S::explain(&self.0, part, &self.1)
+    }
+}
+
+pub fn prepare<S>(input: &str, params: &Params) -> Result<Box<dyn Prepared>>
+where S: Solution + 'static
+{
+    params.check_keys(S::PARAMS)?;
+    Ok(Box::new(PreparedInput::<S>(S::parse_with(input, params)?, params.clone())))
+}
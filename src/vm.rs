@@ -0,0 +1,430 @@
+//! The 3-bit computer from day 17: decoding and running its programs, and
+//! translating them to and from a readable assembly language.
+
+use crate::error::{Error, Result};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    /// Position in the program of the next instruction's opcode.
+    pub ip: usize
+}
+
+impl Registers {
+    /// Registers at the start of a program, with `a` set and the rest cleared.
+    pub fn with_a(a: u64) -> Registers {
+        Registers { a, b: 0, c: 0, ip: 0 }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Literal(u8),
+    RegA,
+    RegB,
+    RegC
+}
+
+impl Operand {
+    fn literal(byte: u8) -> Result<Operand> {
+        match byte {
+            0..=7 => Ok(Operand::Literal(byte)),
+            invalid => Err(Error::solve(format!("invalid literal operand: {}", invalid)))
+        }
+    }
+
+    fn combo(byte: u8) -> Result<Operand> {
+        match byte {
+            b @ 0..=3 => Ok(Operand::Literal(b)),
+            4 => Ok(Operand::RegA),
+            5 => Ok(Operand::RegB),
+            6 => Ok(Operand::RegC),
+            invalid => Err(Error::solve(format!("invalid combo operand: {}", invalid)))
+        }
+    }
+
+    fn encode(&self) -> u8 {
+        match self {
+            Operand::Literal(byte) => *byte,
+            Operand::RegA => 4,
+            Operand::RegB => 5,
+            Operand::RegC => 6
+        }
+    }
+
+    pub fn value(&self, registers: &Registers) -> u64 {
+        match self {
+            Operand::Literal(byte) => *byte as u64,
+            Operand::RegA => registers.a,
+            Operand::RegB => registers.b,
+            Operand::RegC => registers.c
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Literal(byte) => write!(f, "{}", byte),
+            Operand::RegA => write!(f, "a"),
+            Operand::RegB => write!(f, "b"),
+            Operand::RegC => write!(f, "c")
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    Adv(Operand),
+    Bxl(Operand),
+    Bst(Operand),
+    Jnz(Operand),
+    /// The operand is read but ignored; it's kept so the program can be
+    /// assembled back to the same bytes.
+    Bxc(Operand),
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand)
+}
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+// a / 2^operand, which is 0 once the divisor no longer fits
+fn shift_right(a: u64, operand: u64) -> u64 {
+    if operand >= 64 { 0 } else { a >> operand }
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Result<Instruction> {
+        let instruction = match opcode {
+            0 => Instruction::Adv(Operand::combo(operand)?),
+            1 => Instruction::Bxl(Operand::literal(operand)?),
+            2 => Instruction::Bst(Operand::combo(operand)?),
+            3 => Instruction::Jnz(Operand::literal(operand)?),
+            4 => Instruction::Bxc(Operand::literal(operand)?),
+            5 => Instruction::Out(Operand::combo(operand)?),
+            6 => Instruction::Bdv(Operand::combo(operand)?),
+            7 => Instruction::Cdv(Operand::combo(operand)?),
+            invalid => return Err(Error::solve(format!("invalid opcode: {}", invalid)))
+        };
+        Ok(instruction)
+    }
+
+    fn opcode(&self) -> u8 {
+        match self {
+            Instruction::Adv(_) => 0,
+            Instruction::Bxl(_) => 1,
+            Instruction::Bst(_) => 2,
+            Instruction::Jnz(_) => 3,
+            Instruction::Bxc(_) => 4,
+            Instruction::Out(_) => 5,
+            Instruction::Bdv(_) => 6,
+            Instruction::Cdv(_) => 7
+        }
+    }
+
+    pub fn operand(&self) -> Operand {
+        match *self {
+            Instruction::Adv(operand) | Instruction::Bxl(operand) | Instruction::Bst(operand)
+                | Instruction::Jnz(operand) | Instruction::Bxc(operand) | Instruction::Out(operand)
+                | Instruction::Bdv(operand) | Instruction::Cdv(operand) => operand
+        }
+    }
+
+    /// The opcode and operand bytes.
+    pub fn encode(&self) -> [u8; 2] {
+        [self.opcode(), self.operand().encode()]
+    }
+
+    /// Runs the instruction, returning what it outputs along with the registers afterwards.
+    pub fn execute(&self, registers: &Registers) -> (Option<u8>, Registers) {
+        let value = self.operand().value(registers);
+        let next = Registers { ip: registers.ip + 2, ..*registers };
+        match self {
+            Instruction::Adv(_) => (None, Registers { a: shift_right(registers.a, value), ..next }),
+            Instruction::Bdv(_) => (None, Registers { b: shift_right(registers.a, value), ..next }),
+            Instruction::Cdv(_) => (None, Registers { c: shift_right(registers.a, value), ..next }),
+            Instruction::Bxl(_) => (None, Registers { b: registers.b ^ value, ..next }),
+            Instruction::Bst(_) => (None, Registers { b: value % 8, ..next }),
+            Instruction::Jnz(_) if registers.a != 0 => (None, Registers { ip: value as usize, ..next }),
+            Instruction::Jnz(_) => (None, next),
+            Instruction::Bxc(_) => (None, Registers { b: registers.b ^ registers.c, ..next }),
+            Instruction::Out(_) => (Some((value % 8) as u8), next)
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", MNEMONICS[self.opcode() as usize], self.operand())
+    }
+}
+
+/// Every instruction in the program, in order.
+pub fn decode(code: &[u8]) -> Result<Vec<Instruction>> {
+    if !code.len().is_multiple_of(2) {
+        return Err(Error::solve("the program ends in the middle of an instruction"));
+    }
+    code.chunks(2).enumerate()
+        .map(|(idx, pair)| Instruction::decode(pair[0], pair[1])
+            .map_err(|err| Error::solve(format!("instruction {}: {}", idx, err))))
+        .collect()
+}
+
+/// The program as one instruction per line, like `adv 3` or `out b`.
+pub fn disassemble(code: &[u8]) -> Result<String> {
+    Ok(decode(code)?.iter().map(|instruction| format!("{}\n", instruction)).collect())
+}
+
+/// Turns the text `disassemble` produces back into program bytes. Blank
+/// lines and anything after a `#` are ignored, and the operand of `bxc` can
+/// be left out.
+pub fn assemble(text: &str) -> Result<Vec<u8>> {
+    let mut code = vec![];
+    for line in text.lines() {
+        let line = line.split('#').next().unwrap();
+        let mut tokens = line.split_whitespace();
+        let Some(mnemonic) = tokens.next() else {
+            continue;
+        };
+        let opcode = MNEMONICS.iter().position(|&known| known == mnemonic)
+            .ok_or_else(|| Error::at(text, mnemonic, format!("unknown instruction `{}`", mnemonic)))?;
+        let operand = match (tokens.next(), opcode) {
+            (None, 4) => 0,
+            (None, _) => return Err(Error::at(text, &mnemonic[mnemonic.len()..], "expected an operand")),
+            (Some(token), _) => assemble_operand(text, token, matches!(opcode, 1 | 3 | 4))?
+        };
+        if let Some(extra) = tokens.next() {
+            return Err(Error::at(text, extra, "unexpected trailing input"));
+        }
+        code.extend([opcode as u8, operand]);
+    }
+    Ok(code)
+}
+
+fn assemble_operand(text: &str, token: &str, literal: bool) -> Result<u8> {
+    match (token, literal) {
+        ("a", false) => Ok(4),
+        ("b", false) => Ok(5),
+        ("c", false) => Ok(6),
+        _ => match token.parse::<u8>() {
+            Ok(byte) if byte <= 3 || (literal && byte <= 7) => Ok(byte),
+            _ if literal => Err(Error::at(text, token, format!("`{}` is not a number from 0 to 7", token))),
+            _ => Err(Error::at(text, token, format!("`{}` is not a number from 0 to 3 or a register", token)))
+        }
+    }
+}
+
+/// Why `Machine::run` stopped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// The instruction pointer moved past the end of the program.
+    Halted,
+    /// The next instruction is at a breakpoint.
+    Breakpoint(usize),
+    /// The instruction budget ran out.
+    OutOfBudget
+}
+
+/// A program being run one instruction at a time.
+#[derive(Debug, Clone)]
+pub struct Machine<'a> {
+    code: &'a [u8],
+    registers: Registers,
+    output: Vec<u8>,
+    breakpoints: HashSet<usize>,
+    steps: u64,
+    trace: Option<Vec<TraceStep>>
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(code: &'a [u8], registers: Registers) -> Machine<'a> {
+        Machine { code, registers, output: vec![], breakpoints: HashSet::new(), steps: 0, trace: None }
+    }
+
+    /// Makes every later step get recorded in `trace`.
+    pub fn start_trace(&mut self) {
+        self.trace.get_or_insert_with(Vec::new);
+    }
+
+    /// The steps recorded since `start_trace` was called.
+    pub fn trace(&self) -> &[TraceStep] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Everything output so far.
+    pub fn output(&self) -> &[u8] {
+        &self.output
+    }
+
+    /// Number of instructions run so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.registers.ip + 1 >= self.code.len()
+    }
+
+    /// Makes `run` stop before running the instruction at `ip`.
+    pub fn set_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn clear_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    /// The instruction that runs next, or `None` if the machine has halted.
+    pub fn next_instruction(&self) -> Option<Result<Instruction>> {
+        let ip = self.registers.ip;
+        (!self.is_halted()).then(|| Instruction::decode(self.code[ip], self.code[ip + 1]))
+    }
+
+    /// Runs a single instruction, returning what it outputs. Does nothing if
+    /// the machine has halted.
+    pub fn step(&mut self) -> Result<Option<u8>> {
+        let Some(instruction) = self.next_instruction().transpose()? else {
+            return Ok(None);
+        };
+        let (output, registers) = instruction.execute(&self.registers);
+        if let Some(trace) = &mut self.trace {
+            trace.push(TraceStep { step: self.steps, instruction, before: self.registers, after: registers, output });
+        }
+        self.registers = registers;
+        self.output.extend(output);
+        self.steps += 1;
+        Ok(output)
+    }
+
+    /// Runs until the machine halts, reaches a breakpoint or has run `budget`
+    /// more instructions. The instruction it starts at never counts as a
+    /// breakpoint, so calling `run` again resumes past it.
+    pub fn run(&mut self, budget: Option<u64>) -> Result<Stop> {
+        let mut remaining = budget;
+        let mut first = true;
+        loop {
+            if self.is_halted() {
+                return Ok(Stop::Halted);
+            }
+            if !first && self.breakpoints.contains(&self.registers.ip) {
+                return Ok(Stop::Breakpoint(self.registers.ip));
+            }
+            match &mut remaining {
+                Some(0) => return Ok(Stop::OutOfBudget),
+                Some(left) => *left -= 1,
+                None => ()
+            }
+            self.step()?;
+            first = false;
+        }
+    }
+}
+
+/// Runs `code` to the end, returning its output. Fails if it runs more than
+/// `budget` instructions, which is the only way to tell it might never halt.
+pub fn run(code: &[u8], registers: Registers, budget: Option<u64>) -> Result<Vec<u8>> {
+    let mut machine = Machine::new(code, registers);
+    match machine.run(budget)? {
+        Stop::OutOfBudget => {
+            Err(Error::solve(format!("the program didn't halt within {} instructions", machine.steps())))
+        },
+        _ => Ok(machine.output)
+    }
+}
+
+/// One instruction run by a `Machine` while tracing. `before.ip` is where
+/// the instruction is in the program.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    pub step: u64,
+    pub instruction: Instruction,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u8>
+}
+
+fn registers_json(registers: &Registers) -> String {
+    format!(r#"{{"a":{},"b":{},"c":{},"ip":{}}}"#, registers.a, registers.b, registers.c, registers.ip)
+}
+
+impl TraceStep {
+    /// The step as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        let output = self.output.map_or("null".to_string(), |value| value.to_string());
+        format!(
+            r#"{{"step":{},"ip":{},"instruction":"{}","before":{},"after":{},"output":{}}}"#,
+            self.step, self.before.ip, self.instruction,
+            registers_json(&self.before), registers_json(&self.after), output
+        )
+    }
+}
+
+/// How a trace is written out.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// Aligned columns for reading, with `old -> new` for the registers that changed.
+    Table,
+    /// One JSON object per step, for diffing and processing with other tools.
+    JsonLines
+}
+
+impl FromStr for TraceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<TraceFormat, String> {
+        match s {
+            "table" => Ok(TraceFormat::Table),
+            "json" | "jsonl" => Ok(TraceFormat::JsonLines),
+            _ => Err(format!("unknown trace format `{}`", s))
+        }
+    }
+}
+
+impl TraceFormat {
+    pub fn render(&self, trace: &[TraceStep]) -> String {
+        match self {
+            TraceFormat::Table => render_table(trace),
+            TraceFormat::JsonLines => trace.iter().map(|step| step.to_json() + "\n").collect()
+        }
+    }
+}
+
+fn render_table(trace: &[TraceStep]) -> String {
+    let change = |before: u64, after: u64| if before == after {
+        after.to_string()
+    } else {
+        format!("{} -> {}", before, after)
+    };
+    let mut rows = vec![["step", "ip", "instruction", "a", "b", "c", "out"].map(str::to_string)];
+    for step in trace {
+        rows.push([
+            step.step.to_string(),
+            step.before.ip.to_string(),
+            step.instruction.to_string(),
+            change(step.before.a, step.after.a),
+            change(step.before.b, step.after.b),
+            change(step.before.c, step.after.c),
+            step.output.map_or(String::new(), |value| value.to_string())
+        ]);
+    }
+    let widths: [usize; 7] = std::array::from_fn(|column| rows.iter().map(|row| row[column].len()).max().unwrap());
+    let mut table = String::new();
+    for row in rows {
+        let cells: Vec<String> = row.iter().zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
@@ -0,0 +1,38 @@
+use aoc_2024::days;
+use aoc_2024::error::Error;
+use aoc_2024::params::Params;
+
+const MACHINE: &str = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+
+fn params(pairs: &[(&str, &str)]) -> Params {
+    let mut params = Params::new();
+    for (key, value) in pairs {
+        params.set(*key, *value);
+    }
+    params
+}
+
+#[test]
+fn overflow_is_an_error() {
+    let day13 = days::get(13).unwrap();
+
+    let parsed = day13.parse_with(MACHINE, &params(&[("offset", &i64::MAX.to_string())])).unwrap();
+    assert_eq!(parsed.part_1(), Ok("280".to_string()));
+    assert!(matches!(parsed.part_2(), Err(Error::Param(_))));
+
+    // 80 presses of A cost more than an i64 holds
+    let parsed = day13.parse_with(MACHINE, &params(&[("a_cost", &(i64::MAX / 50).to_string())])).unwrap();
+    assert!(matches!(parsed.part_1(), Err(Error::Solve(_))));
+
+    // each machine fits, but not their total
+    let two_machines = format!("{}\n{}", MACHINE, MACHINE);
+    let cost = (i64::MAX / 100).to_string();
+    let parsed = day13.parse_with(&two_machines, &params(&[("a_cost", &cost), ("b_cost", &cost)])).unwrap();
+    assert_eq!(parsed.part_1(), Err(Error::solve("the number of tokens overflows")));
+
+    // free presses of A all the way up to i64::MAX - 1
+    let collinear = "Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=0, Y=0\n";
+    let offset = (i64::MAX - 1).to_string();
+    let parsed = day13.parse_with(collinear, &params(&[("offset", &offset), ("a_cost", "0")])).unwrap();
+    assert_eq!(parsed.part_2(), Ok("0".to_string()));
+}
@@ -0,0 +1,44 @@
+use aoc_2024::bignum::BigUint;
+
+fn big(s: &str) -> BigUint {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() {
+    for s in ["0", "7", "1000000000000000000", "123456789012345678901234567890"] {
+        assert_eq!(big(s).to_string(), s);
+    }
+    assert_eq!(big("007").to_string(), "7");
+    assert_eq!(BigUint::from(u64::MAX).to_string(), u64::MAX.to_string());
+    assert!("12a".parse::<BigUint>().is_err());
+    assert!("".parse::<BigUint>().is_err());
+}
+
+#[test]
+fn digits() {
+    assert_eq!(big("0").num_digits(), 1);
+    assert_eq!(big("1000000000000000000").num_digits(), 19);
+    let (high, low) = big("123456789012345678901234567890").split_digits(15);
+    assert_eq!((high.to_string(), low.to_string()), ("123456789012345".to_string(), "678901234567890".to_string()));
+    let (high, low) = big("1000000000000000000000").split_digits(20);
+    assert_eq!((high.to_string(), low.to_string()), ("10".to_string(), "0".to_string()));
+    let (high, low) = big("42").split_digits(5);
+    assert_eq!((high.to_string(), low.to_string()), ("0".to_string(), "42".to_string()));
+}
+
+#[test]
+fn multiplication() {
+    assert_eq!(big("99999999999999999999999").mul_small(2024).to_string(), "202399999999999999999997976");
+    assert_eq!(big("0").mul_small(2024), big("0"));
+    assert_eq!(BigUint::from(u64::MAX).mul_small(2).to_u64(), None);
+    assert_eq!(BigUint::from(u64::MAX / 2).mul_small(2).to_u64(), Some(u64::MAX - 1));
+}
+
+#[test]
+fn addition() {
+    assert_eq!(big("999999999999999999").add_small(1).to_string(), "1000000000000000000");
+    assert_eq!(big("0").add_small(u64::MAX).to_string(), u64::MAX.to_string());
+    assert_eq!(big("999999999999999999999999999999999999").add_small(u64::MAX).to_string(),
+        "1000000000000000018446744073709551614");
+}
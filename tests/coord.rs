@@ -0,0 +1,41 @@
+use aoc_2024::coord::{Coord, Direction};
+use aoc_2024::grid::Grid;
+
+#[test]
+fn turns_go_around_all_directions() {
+    for direction in Direction::ALL {
+        let mut turned = direction;
+        for _ in 0..4 {
+            turned = turned.turn_right();
+            assert_eq!(turned.is_cardinal(), direction.is_cardinal());
+        }
+        assert_eq!(turned, direction);
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.opposite().to_coord(), -direction.to_coord());
+        assert_eq!(direction.turn_right().to_coord(), direction.to_coord().rotate_right());
+    }
+    assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+    assert_eq!(Direction::NorthWest.turn_left(), Direction::SouthWest);
+    assert_eq!(Direction::ALL.iter().filter(|direction| direction.is_cardinal()).count(), 4);
+    assert_eq!(Coord(3, 4) + Direction::North, Coord(3, 3));
+}
+
+#[test]
+fn neighbors_stay_in_bounds() {
+    // 3 wide and 2 high, each cell holding its own index
+    let grid = Grid::try_from_vec((0..6).collect(), 3).unwrap();
+    let neighbors4 = |coord| grid.neighbors4(coord).map(|(_, &cell)| cell).collect::<Vec<i32>>();
+    let neighbors8 = |coord| grid.neighbors8(coord).map(|(_, &cell)| cell).collect::<Vec<i32>>();
+    assert_eq!(neighbors4(Coord(0, 0)), [1, 3]);
+    assert_eq!(neighbors8(Coord(0, 0)), [1, 4, 3]);
+    assert_eq!(neighbors4(Coord(2, 1)), [2, 4]);
+    assert_eq!(neighbors8(Coord(2, 1)), [2, 4, 1]);
+    // on the top edge
+    assert_eq!(neighbors4(Coord(1, 0)), [2, 4, 0]);
+    assert_eq!(neighbors8(Coord(1, 0)), [2, 5, 4, 3, 0]);
+    // just outside the grid, only the cells it touches
+    assert_eq!(neighbors8(Coord(-1, -1)), [0]);
+    assert_eq!(grid.neighbors4(Coord(0, 1)).next(), Some((Coord(0, 0), &0)));
+}
@@ -0,0 +1,40 @@
+use aoc_2024::days::day9::{Compactor, DiskMap, Strategy};
+use aoc_2024::error::Error;
+
+const SAMPLE: &str = "2333133121414131402";
+
+#[test]
+fn parse_display_and_encode_round_trip() {
+    let disk_map = DiskMap::parse(SAMPLE).unwrap();
+    assert_eq!(disk_map.to_string(), "00...111...2...333.44.5555.6666.777.888899");
+    assert_eq!(disk_map.encode(), SAMPLE);
+    assert_eq!(DiskMap::parse("1,2,0,3").unwrap().encode(), "1203");
+    assert_eq!(DiskMap::parse("10,2,3").unwrap().encode(), "10,2,3");
+    assert_eq!(DiskMap::parse("\n\n12345\n").unwrap().encode(), "12345");
+    assert_eq!(
+        DiskMap::parse("\n123x5\n"),
+        Err(Error::Parse { line: 2, column: 4, message: "invalid character `x`".to_string() })
+    );
+}
+
+#[test]
+fn compaction_steps() {
+    let disk_map = DiskMap::parse(SAMPLE).unwrap();
+    let mut compactor = Compactor::new(&disk_map, Strategy::Blocks);
+    let first = compactor.next().unwrap();
+    assert_eq!((first.file, first.len, first.from, first.to), (9, 2, 40, 2));
+    assert_eq!(compactor.disk_map().to_string(), "0099.111...2...333.44.5555.6666.777.8888..");
+    assert_eq!(compactor.by_ref().count(), 6);
+    assert_eq!(compactor.checksum(), 1928);
+    assert_eq!(compactor.disk_map().checksum(), 1928);
+
+    let mut compactor = Compactor::new(&disk_map, Strategy::Files);
+    assert_eq!(compactor.by_ref().count(), 4);
+    let compacted = compactor.disk_map();
+    assert_eq!(compacted.to_string(), "00992111777.44.333....5555.6666.....8888..");
+    assert_eq!(compacted.checksum(), 2858);
+    // the layout survives encoding even though the ids don't
+    let reencoded = DiskMap::parse(&compacted.encode()).unwrap();
+    assert_eq!(reencoded.to_string().replace(|c: char| c.is_ascii_digit(), "#"),
+        compacted.to_string().replace(|c: char| c.is_ascii_digit(), "#"));
+}
@@ -0,0 +1,36 @@
+use aoc_2024::fixtures;
+
+macro_rules! examples {
+    ($($name:ident: $number:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                if let Err(err) = fixtures::check_day($number) {
+                    panic!("{}", err);
+                }
+            }
+        )*
+    };
+}
+
+examples! {
+    day1: 1,
+    day2: 2,
+    day3: 3,
+    day4: 4,
+    day5: 5,
+    day6: 6,
+    day7: 7,
+    day8: 8,
+    day9: 9,
+    day10: 10,
+    day11: 11,
+    day12: 12,
+    day13: 13,
+    day14: 14,
+    day15: 15,
+    day16: 16,
+    day17: 17,
+    day18: 18,
+    day19: 19,
+}
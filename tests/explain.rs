@@ -0,0 +1,76 @@
+use aoc_2024::days;
+use aoc_2024::input;
+use aoc_2024::params::Params;
+
+fn explain(day: u32, fixture: &str, pairs: &[(&str, &str)], part: u32) -> Option<String> {
+    let text = input::read_file(input::day_dir(day).join(format!("{}.txt", fixture))).unwrap();
+    let mut params = Params::new();
+    for (key, value) in pairs {
+        params.set(*key, *value);
+    }
+    days::get(day).unwrap().parse_with(&text, &params).unwrap().explain(part).unwrap()
+}
+
+#[test]
+fn day14_draws_the_robots() {
+    let size = [("width", "11"), ("height", "7")];
+    assert_eq!(explain(14, "sample", &size, 1), None);
+    let picture = explain(14, "sample", &size, 2).unwrap();
+    assert_eq!(picture.lines().count(), 7);
+    assert!(picture.lines().all(|line| line.len() == 11 && line.chars().all(|c| c == '.' || c == '#')));
+    assert!(picture.contains('#'));
+}
+
+#[test]
+fn day13_describes_each_machine() {
+    assert_eq!(
+        explain(13, "collinear", &[], 1).unwrap(),
+        "machine 1: many ways, the cheapest is 0 A and 4 B\nmachine 2: no way\nmachine 3: many ways, the cheapest is 0 A and 3 B"
+    );
+    assert_eq!(explain(13, "sample", &[], 1).unwrap().lines().next(), Some("machine 1: one way, 80 A and 40 B"));
+}
+
+#[test]
+fn day17_traces_the_run_and_lists_every_quine() {
+    let table = explain(17, "sample", &[], 1).unwrap();
+    assert_eq!(table.lines().nth(1), Some("0     0   adv 1        729 -> 364  0  0"));
+    let json = explain(17, "sample", &[("trace", "json")], 1).unwrap();
+    assert!(json.lines().all(|line| line.starts_with("{\"step\":")));
+    assert_eq!(
+        explain(17, "quine_sample", &[], 2).unwrap(),
+        "all 8 values: 117440, 117441, 117442, 117443, 117444, 117445, 117446, 117447"
+    );
+}
+
+#[test]
+fn day6_describes_each_loop() {
+    let witnesses = explain(6, "sample", &[], 2).unwrap();
+    assert_eq!(witnesses.lines().count(), 6);
+    assert_eq!(
+        witnesses.lines().next(),
+        Some("obstacle at (3, 6): loops from (4, 6) facing North after 18 steps, heading North, East, South, West")
+    );
+    let rendered = explain(6, "sample", &[("render", "true")], 2).unwrap();
+    assert_eq!(rendered.lines().nth(7), Some(".#.O^---+."));
+    assert_eq!(explain(6, "sample", &[], 1), None);
+}
+
+#[test]
+fn day9_shows_every_move() {
+    let blocks = explain(9, "sample", &[], 1).unwrap();
+    let mut lines = blocks.lines();
+    assert_eq!(lines.next(), Some("00...111...2...333.44.5555.6666.777.888899"));
+    assert_eq!(lines.next(), Some("file 9: 2 blocks from 40 to 2"));
+    assert_eq!(blocks.lines().last(), Some("0099811188827773336446555566.............."));
+    let files = explain(9, "sample", &[], 2).unwrap();
+    assert_eq!(files.lines().nth(3), Some("file 7: 3 blocks from 32 to 8"));
+    assert_eq!(files.lines().last(), Some("00992111777.44.333....5555.6666.....8888.."));
+}
+
+#[test]
+fn day11_reports_whether_the_stones_repeat() {
+    assert_eq!(explain(11, "sample", &[], 1).unwrap(), "the stones never repeat");
+    let counting = [("rules", "odd digits -> x + 1; even digits -> left"), ("blinks_2", "10000")];
+    assert_eq!(explain(11, "sample", &counting, 1).unwrap(), "the stones never repeat");
+    assert_eq!(explain(11, "sample", &counting, 2).unwrap(), "the stones after 1024 blinks repeat every 10 blinks");
+}
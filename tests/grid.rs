@@ -0,0 +1,43 @@
+use aoc_2024::coord::Coord;
+use aoc_2024::error::Error;
+use aoc_2024::grid::{Grid, Tile};
+
+fn parse_error(line: usize, column: usize, message: &str) -> Error {
+    Error::Parse { line, column, message: message.to_string() }
+}
+
+#[test]
+fn parse_ignores_blank_lines_around_the_map() {
+    let grid: Grid<Tile> = Grid::parse("\n\n.#\n#.\n\n").unwrap();
+    assert_eq!((grid.width, grid.height), (2, 2));
+    assert_eq!(grid.get(Coord(1, 0)), Some(&Tile::Wall));
+    assert_eq!(grid.to_string(), ".#\n#.\n");
+}
+
+#[test]
+fn parse_errors_point_at_the_problem() {
+    assert_eq!(Grid::<Tile>::parse("..\n.x\n").err(), Some(parse_error(2, 2, "invalid character `x`")));
+    assert_eq!(Grid::<Tile>::parse("..\n...\n").err(), Some(parse_error(2, 1, "expected a row of width 2")));
+    assert_eq!(Grid::<Tile>::parse("\n...\n.\n").err(), Some(parse_error(3, 1, "expected a row of width 3")));
+    assert_eq!(Grid::<Tile>::parse("\n\n").err(), Some(parse_error(1, 1, "empty map")));
+}
+
+#[test]
+fn markers_appear_exactly_once() {
+    let (grid, [start, end]) = Grid::<Tile>::parse_with_markers("S.#\n..E\n", ['S', 'E']).unwrap();
+    assert_eq!((start, end), (Coord(0, 0), Coord(2, 1)));
+    assert_eq!(grid.get(start), Some(&Tile::Open));
+
+    assert_eq!(
+        Grid::<Tile>::parse_with_markers("S.S\n..E\n", ['S', 'E']).err(),
+        Some(parse_error(1, 3, "`S` appears more than once"))
+    );
+    assert_eq!(
+        Grid::<Tile>::parse_with_markers("S..\n...\n", ['S', 'E']).err(),
+        Some(parse_error(1, 1, "`E` not found"))
+    );
+    assert_eq!(
+        Grid::<Tile>::parse_with_markers("S..\n.?E\n", ['S', 'E']).err(),
+        Some(parse_error(2, 2, "invalid character `?`"))
+    );
+}
@@ -0,0 +1,51 @@
+use aoc_2024::modular::{self, Torus};
+
+#[test]
+fn new_rejects_empty_axes() {
+    assert!(Torus::new([11, 7]).is_ok());
+    assert!(Torus::new([0, 7]).is_err());
+    assert!(Torus::new([11, -7]).is_err());
+}
+
+#[test]
+fn positions_wrap_around() {
+    let space = Torus::new([11, 7]).unwrap();
+    // the robot from the puzzle's example
+    assert_eq!(space.position_after([2, 4], [2, -3], 5), [1, 3]);
+    assert_eq!(space.position_after([0, 0], [-1, -8], 1), [10, 6]);
+    assert_eq!(space.position_after([3, 5], [0, 0], 1_000_000), [3, 5]);
+    assert_eq!(space.position_after([3, 5], [-4, 2], 77 * 1_000_000_000_000), [3, 5]);
+    assert_eq!(modular::position_after(-1, -3, 2, 5), 3);
+    assert_eq!(modular::mul_mod(i64::MAX, -3, 7), (i64::MAX as i128 * -3).rem_euclid(7) as i64);
+}
+
+#[test]
+fn orthants_skip_midlines() {
+    let space = Torus::new([11, 7]).unwrap();
+    assert_eq!(space.orthant([0, 0]), Some(0));
+    assert_eq!(space.orthant([6, 0]), Some(1));
+    assert_eq!(space.orthant([4, 4]), Some(2));
+    assert_eq!(space.orthant([10, 6]), Some(3));
+    assert_eq!(space.orthant([5, 0]), None);
+    assert_eq!(space.orthant([0, 3]), None);
+    // even lengths have no middle position
+    let even = Torus::new([4, 6]).unwrap();
+    assert_eq!(even.orthant([1, 2]), Some(0));
+    assert_eq!(even.orthant([2, 3]), Some(3));
+}
+
+#[test]
+fn periods() {
+    let space = Torus::new([11, 7]).unwrap();
+    assert_eq!(space.period([2, -3]), 77);
+    assert_eq!(space.period([0, 0]), 1);
+    assert_eq!(space.period([0, -7]), 1);
+    assert_eq!(space.period([22, 1]), 7);
+
+    let small = Torus::new([4, 6]).unwrap();
+    assert_eq!(small.period([2, 3]), 2);
+    assert_eq!(small.period([-1, 0]), 4);
+    assert_eq!(small.common_period([[2, 3], [-1, 0]]), 4);
+    assert_eq!(small.common_period([[2, -2]]), 6);
+    assert_eq!(small.common_period([]), 1);
+}
@@ -0,0 +1,82 @@
+use aoc_2024::numtheory::{self, LinearSolution};
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(numtheory::gcd(12, 18), 6);
+    assert_eq!(numtheory::gcd(-12, 18), 6);
+    assert_eq!(numtheory::gcd(0, 7), 7);
+    assert_eq!(numtheory::gcd(0, 0), 0);
+    assert_eq!(numtheory::lcm(4, 6), 12);
+    assert_eq!(numtheory::lcm(-4, 6), 12);
+    assert_eq!(numtheory::lcm(0, 6), 0);
+}
+
+#[test]
+fn egcd_satisfies_bezout() {
+    for (a, b) in [(240, 46), (46, 240), (-240, 46), (17, 0), (0, -5), (1_000_000_007, 998_244_353)] {
+        let (g, x, y) = numtheory::egcd(a, b);
+        assert_eq!(g, numtheory::gcd(a, b));
+        assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, g as i128);
+    }
+}
+
+#[test]
+fn mod_inverse() {
+    assert_eq!(numtheory::mod_inverse(3, 11), Some(4));
+    assert_eq!(numtheory::mod_inverse(-3, 11), Some(7));
+    assert_eq!(numtheory::mod_inverse(6, 9), None);
+    assert_eq!(numtheory::mod_inverse(5, 1), Some(0));
+}
+
+#[test]
+fn crt() {
+    assert_eq!(numtheory::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(numtheory::crt(&[(-1, 101), (-1, 103)]), Some((10402, 10403)));
+    // moduli with a common factor
+    assert_eq!(numtheory::crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+    assert_eq!(numtheory::crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(numtheory::crt(&[]), Some((0, 1)));
+}
+
+#[test]
+fn solve_diophantine() {
+    let ((x, y), (dx, dy)) = numtheory::solve_diophantine(94, 22, 8400).unwrap();
+    for k in -3..3 {
+        assert_eq!(94 * (x + k * dx) + 22 * (y + k * dy), 8400);
+    }
+    assert_eq!(numtheory::solve_diophantine(4, 6, 5), None);
+
+    // the Bezout coefficients times c are far outside i64
+    let c = 1_000_000_000_000_000_000;
+    let ((x, y), (dx, dy)) = numtheory::solve_diophantine(1_000_000_007, 998_244_353, c).unwrap();
+    assert!((0..998_244_353).contains(&x));
+    assert_eq!(1_000_000_007 * x as i128 + 998_244_353 * y as i128, c as i128);
+    assert_eq!((dx, dy), (998_244_353, -1_000_000_007));
+    assert_eq!(numtheory::solve_diophantine(-3, 0, 12), Some(((-4, 0), (0, 1))));
+    assert_eq!(numtheory::solve_diophantine(1, 2, i64::MAX - 1), Some(((0, i64::MAX / 2), (2, -1))));
+    // the step -a doesn't fit
+    assert_eq!(numtheory::solve_diophantine(i64::MIN, 1, 0), None);
+}
+
+#[test]
+fn solve_2x2() {
+    assert_eq!(numtheory::solve_2x2([[94, 22], [34, 67]], [8400, 5400]), Some(LinearSolution::Unique(80, 40)));
+    assert_eq!(numtheory::solve_2x2([[26, 67], [66, 21]], [12748, 12176]), Some(LinearSolution::NoSolution));
+    assert_eq!(numtheory::solve_2x2([[1, 2], [2, 4]], [3, 6]), Some(LinearSolution::Infinite));
+    assert_eq!(numtheory::solve_2x2([[1, 2], [2, 4]], [3, 7]), Some(LinearSolution::NoSolution));
+    assert_eq!(numtheory::solve_2x2([[2, 4], [3, 6]], [3, 4]), Some(LinearSolution::NoSolution));
+    assert_eq!(numtheory::solve_2x2([[2, 4], [1, 2]], [5, 2]), Some(LinearSolution::NoSolution));
+    assert_eq!(numtheory::solve_2x2([[0, 0], [0, 0]], [0, 0]), Some(LinearSolution::Infinite));
+    assert_eq!(numtheory::solve_2x2([[0, 0], [1, 1]], [0, 5]), Some(LinearSolution::Infinite));
+    assert_eq!(numtheory::solve_2x2([[0, 0], [1, 1]], [1, 5]), Some(LinearSolution::NoSolution));
+    // x is 2^63, one more than i64::MAX
+    assert_eq!(numtheory::solve_2x2([[-1, 0], [0, 1]], [i64::MIN, 0]), None);
+}
+
+#[test]
+fn rounded_division() {
+    for (a, b, floor, ceil) in [(7, 2, 3, 4), (-7, 2, -4, -3), (7, -2, -4, -3), (-7, -2, 3, 4), (6, 3, 2, 2), (-6, 3, -2, -2), (0, 5, 0, 0)] {
+        assert_eq!(numtheory::div_floor(a, b), floor, "{} / {}", a, b);
+        assert_eq!(numtheory::div_ceil(a, b), ceil, "{} / {}", a, b);
+    }
+}
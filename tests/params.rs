@@ -0,0 +1,32 @@
+use aoc_2024::days;
+use aoc_2024::error::Error;
+use aoc_2024::params::Params;
+
+#[test]
+fn get_parses_or_defaults() {
+    let mut params = Params::new();
+    params.set("size", "7");
+    params.set("render", "maybe");
+    assert_eq!(params.get("size", 71), Ok(7));
+    assert_eq!(params.get("fallen", 1024), Ok(1024));
+    assert_eq!(params.get_optional::<u32>("fallen"), Ok(None));
+    assert!(matches!(params.get("render", false), Err(Error::Param(_))));
+}
+
+#[test]
+fn unknown_keys_are_errors() {
+    let mut params = Params::new();
+    params.set("size", "7");
+    assert_eq!(params.check_keys(&["size", "fallen"]), Ok(()));
+    params.set("sise", "7");
+    assert_eq!(
+        params.check_keys(&["size", "fallen"]),
+        Err(Error::Param("unknown parameter `sise`, expected one of: size, fallen".to_string()))
+    );
+    assert!(params.check_keys(&[]).is_err());
+
+    // checked before the input is even parsed
+    let day18 = days::get(18).unwrap();
+    assert!(matches!(day18.parse_with("", &params), Err(Error::Param(_))));
+    assert!(matches!(days::get(1).unwrap().parse_with("", &params), Err(Error::Param(_))));
+}
@@ -0,0 +1,17 @@
+use aoc_2024::days;
+use aoc_2024::error::Error;
+
+#[test]
+fn guard_that_never_leaves_is_an_error() {
+    let day6 = days::get(6).unwrap();
+    let error = Err(Error::solve("the guard walks in a loop and never leaves the lab"));
+    // boxed in on every side, so she only turns
+    let boxed_in = day6.parse(".#.\n#^#\n.#.\n").unwrap();
+    assert_eq!(boxed_in.part_1(), error);
+    assert!(boxed_in.part_2().is_err());
+    // walls along a loop
+    let walled_loop = day6.parse(".#...\n....#\n#^...\n...#.\n").unwrap();
+    assert_eq!(walled_loop.part_1(), error);
+    assert!(walled_loop.part_2().is_err());
+    assert_eq!(day6.parse(".#.\n#^.\n.#.\n").unwrap().part_1(), Ok("2".to_string()));
+}
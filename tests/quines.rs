@@ -0,0 +1,21 @@
+use aoc_2024::days;
+use aoc_2024::error::Error;
+
+fn quine(program: &str) -> Result<String, Error> {
+    let input = format!("Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n", program);
+    days::get(17).unwrap().parse(&input)?.part_2()
+}
+
+#[test]
+fn registers_carried_between_passes_are_rejected() {
+    assert_eq!(quine("0,3,5,4,3,0"), Ok("117440".to_string()));
+    // bxl 1, adv 3, out b, jnz 0: B flips every pass, so passes depend on each other
+    assert_eq!(
+        quine("1,1,0,3,5,5,3,0"),
+        Err(Error::solve("can't search for a quine: `bxl 1` reads B before the pass writes it, which this search doesn't support"))
+    );
+    // bst a, adv 3, out c, jnz 0
+    assert!(quine("2,4,0,3,5,6,3,0").is_err_and(|err| err.to_string().contains("`out c` reads C")));
+    // cdv 1 writes C before bxc reads it, so the search runs and finds nothing
+    assert!(quine("7,1,2,4,4,0,0,3,5,5,3,0").is_err_and(|err| err.to_string().starts_with("no value of register A")));
+}
@@ -0,0 +1,115 @@
+use aoc_2024::coord::{Coord, Direction};
+use aoc_2024::grid::{Grid, Tile};
+use aoc_2024::{input, search};
+use std::collections::HashSet;
+
+// 0 -> 1 -> 3 -> 4 and 0 -> 2 -> 3, with 5 cut off from the rest
+const EDGES: [(u32, u32, u64); 6] = [(0, 1, 1), (0, 2, 4), (1, 3, 5), (2, 3, 1), (3, 4, 3), (5, 4, 1)];
+
+fn successors(state: &u32) -> Vec<(u32, u64)> {
+    EDGES.iter()
+        .filter(|&&(from, _, _)| from == *state)
+        .map(|&(_, to, cost)| (to, cost))
+        .collect()
+}
+
+fn neighbors(state: &u32) -> Vec<u32> {
+    successors(state).into_iter().map(|(to, _)| to).collect()
+}
+
+#[test]
+fn bfs_counts_steps() {
+    let found = search::bfs(0, neighbors, |&state| state == 4).unwrap();
+    assert_eq!(found.cost, 3);
+    assert_eq!(found.path.len(), 4);
+    assert_eq!((found.path[0], found.path[3]), (0, 4));
+    assert_eq!(search::bfs(0, neighbors, |&state| state == 0).unwrap().path, [0]);
+    assert!(search::bfs(0, neighbors, |&state| state == 5).is_none());
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_path() {
+    let found = search::dijkstra(0, successors, |&state| state == 4).unwrap();
+    assert_eq!(found.cost, 8);
+    assert_eq!(found.path, [0, 2, 3, 4]);
+    assert_eq!(search::reconstruct_path(&found.predecessors, 3), [0, 2, 3]);
+    assert!(search::dijkstra(0, successors, |&state| state == 5).is_none());
+    assert!(search::dijkstra(4, successors, |&state| state == 0).is_none());
+}
+
+#[test]
+fn astar_agrees_with_dijkstra() {
+    for goal in 0..6 {
+        let plain = search::dijkstra(0, successors, |&state| state == goal);
+        let guided = search::astar(0, successors, |&state| state == goal, |_| 0);
+        assert_eq!(plain.map(|found| (found.cost, found.path)), guided.map(|found| (found.cost, found.path)));
+    }
+    // an admissible heuristic: the cheapest edge count left times the cheapest edge
+    let remaining = |state: &u32| search::bfs(*state, neighbors, |&s| s == 4).map_or(0, |found| found.cost as u64);
+    let guided = search::astar(0, successors, |&state| state == 4, remaining).unwrap();
+    assert_eq!((guided.cost, guided.path), (8, vec![0, 2, 3, 4]));
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Reindeer {
+    location: Coord,
+    direction: Direction
+}
+
+fn maze_successors(grid: &Grid<Tile>, state: &Reindeer) -> Vec<(Reindeer, u64)> {
+    let &Reindeer { location, direction } = state;
+    let mut next = vec![
+        (Reindeer { location, direction: direction.turn_left() }, 1000),
+        (Reindeer { location, direction: direction.turn_right() }, 1000)
+    ];
+    if grid.get(location + direction) == Some(&Tile::Open) {
+        next.push((Reindeer { location: location + direction, direction }, 1));
+    }
+    next
+}
+
+#[test]
+fn all_cheapest_paths_through_a_maze() {
+    let text = input::read_file(input::day_dir(16).join("second_sample.txt")).unwrap();
+    let (grid, [start, end]) = Grid::<Tile>::parse_with_markers(&text, ['S', 'E']).unwrap();
+    let found = search::dijkstra_all(
+        Reindeer { location: start, direction: Direction::East },
+        |state| maze_successors(&grid, state),
+        |state| state.location == end
+    ).unwrap();
+    assert_eq!(found.cost, 11048);
+
+    let paths: Vec<Vec<Reindeer>> = found.paths().collect();
+    assert_eq!(paths.len(), 2);
+    let mut on_paths = HashSet::new();
+    for path in &paths {
+        assert_eq!(path.first().map(|state| state.location), Some(start));
+        assert_eq!(path.last().map(|state| state.location), Some(end));
+        let cost: u64 = path.windows(2)
+            .map(|step| if step[0].location == step[1].location { 1000 } else { 1 })
+            .sum();
+        assert_eq!(cost, found.cost);
+        on_paths.extend(path.iter().copied());
+    }
+    assert_eq!(on_paths, found.states());
+    let tiles: HashSet<Coord> = on_paths.iter().map(|state| state.location).collect();
+    assert_eq!(tiles.len(), 64);
+}
+
+#[test]
+fn all_cheapest_paths_with_no_way_to_the_goal() {
+    assert!(search::dijkstra_all(0, successors, |&state| state == 5).is_none());
+    // both ways to 3 cost the same
+    let tied = |state: &u32| -> Vec<(u32, u64)> {
+        match state {
+            0 => vec![(1, 1), (2, 1)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![]
+        }
+    };
+    let found = search::dijkstra_all(0, tied, |&state| state == 3).unwrap();
+    let mut paths: Vec<Vec<u32>> = found.paths().collect();
+    paths.sort();
+    assert_eq!(paths, [[0, 1, 3], [0, 2, 3]]);
+    assert!(search::dijkstra_all(0, tied, |&state| state == 4).is_none());
+}
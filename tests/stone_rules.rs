@@ -0,0 +1,36 @@
+use aoc_2024::bignum::BigUint;
+use aoc_2024::days::day11::{self, Cycle, Histogram, RuleSet};
+
+fn stones(numbers: &[u64]) -> Histogram {
+    let mut stones = Histogram::new();
+    for &number in numbers {
+        *stones.entry(BigUint::from(number)).or_default() += 1;
+    }
+    stones
+}
+
+#[test]
+fn puzzle_rules_from_spec() {
+    let spec = "0 -> 1 # zero\neven digits -> left, right\n\nany -> x * 2024";
+    let rules = RuleSet::parse(spec).unwrap();
+    assert_eq!(rules, RuleSet::default());
+    assert_eq!(RuleSet::parse("0 -> 1; even digits -> left,right; any -> x*2024").unwrap(), rules);
+    let commented = "0 -> 1 # zero; becomes one\neven digits -> left, right; any -> x * 2024 # x; the rest";
+    assert_eq!(RuleSet::parse(commented).unwrap(), rules);
+    let evolution = day11::evolve(&stones(&[125, 17]), &rules, 25).unwrap();
+    assert_eq!((evolution.count, evolution.cycle), (55312, None));
+    assert!(RuleSet::parse("0 => 1").is_err());
+    assert!(RuleSet::parse("odd -> x").is_err());
+}
+
+#[test]
+fn cycles_cut_blinks_short() {
+    let rules = RuleSet::parse("1 -> 2; 2 -> 3, 4; 3 -> 1; 4 -> 0; 0 -> 1; any -> x + 1").unwrap();
+    let evolution = day11::evolve(&stones(&[7]), &rules, 1_000_000).unwrap();
+    assert_eq!(evolution.cycle, None);
+    let rules = RuleSet::parse("odd digits -> x + 1; even digits -> left").unwrap();
+    let evolution = day11::evolve(&stones(&[8, 8, 3]), &rules, u64::MAX).unwrap();
+    assert_eq!(evolution.count, 3);
+    // 1 to 9 count up to 10, which goes back to 1
+    assert!(matches!(evolution.cycle, Some(Cycle { length: 10, .. })));
+}
@@ -0,0 +1,78 @@
+use aoc_2024::days::{self, day19::Towels};
+use aoc_2024::error::Error;
+use aoc_2024::params::Params;
+
+const PATTERNS: [&str; 8] = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+const DESIGNS: [&str; 8] = ["brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb"];
+
+fn arrangements(towels: &Towels, design: &str) -> Vec<Vec<String>> {
+    towels.arrangements(design.as_bytes())
+        .map(|arrangement| arrangement.iter().map(|towel| String::from_utf8(towel.to_vec()).unwrap()).collect())
+        .collect()
+}
+
+#[test]
+fn sample_arrangements() {
+    let towels = Towels::new(PATTERNS);
+    assert_eq!(arrangements(&towels, "brwrr"), [vec!["b", "r", "wr", "r"], vec!["br", "wr", "r"]]);
+    assert_eq!(arrangements(&towels, "bggr"), [vec!["b", "g", "g", "r"]]);
+    assert_eq!(arrangements(&towels, "gbbr"), [
+        vec!["g", "b", "b", "r"],
+        vec!["g", "b", "br"],
+        vec!["gb", "b", "r"],
+        vec!["gb", "br"]
+    ]);
+    assert!(arrangements(&towels, "ubwu").is_empty());
+    for design in DESIGNS {
+        let listed = arrangements(&towels, design);
+        assert_eq!(Some(listed.len() as u64), towels.count_arrangements(design.as_bytes()));
+        for arrangement in listed {
+            assert_eq!(arrangement.concat(), design);
+        }
+    }
+}
+
+#[test]
+fn nth_arrangement_matches_listing() {
+    let towels = Towels::new(PATTERNS);
+    for design in DESIGNS.map(str::as_bytes) {
+        let count = towels.count_arrangements(design).unwrap();
+        for index in 0..count + 2 {
+            assert_eq!(towels.nth_arrangement(design, index), towels.arrangements(design).nth(index as usize));
+        }
+        assert_eq!(towels.nth_arrangement(design, u64::MAX), None);
+    }
+}
+
+#[test]
+fn too_many_arrangements_to_count() {
+    let towels = Towels::new(["a", "aa"]);
+    // Fibonacci numbers, which pass u64::MAX at the 94th
+    let design = [b'a'; 100];
+    assert_eq!(towels.count_arrangements(&design[..90]), Some(4660046610375530309));
+    assert_eq!(towels.count_arrangements(&design), None);
+    assert_eq!(towels.nth_arrangement(&design, 0), Some(vec![&b"a"[..]; 100]));
+    assert_eq!(towels.nth_arrangement(&design, u64::MAX - 1).map(|towels| towels.concat().len()), Some(100));
+    assert_eq!(towels.arrangements(&design).nth(1).map(|towels| towels.len()), Some(99));
+
+    let day19 = days::get(19).unwrap();
+    let input = format!("a, aa\n\n{}\n", "a".repeat(100));
+    let parsed = day19.parse(&input).unwrap();
+    assert_eq!(parsed.part_1(), Ok("1".to_string()));
+    assert!(matches!(parsed.part_2(), Err(Error::Solve(_))));
+}
+
+#[test]
+fn explanation_lists_arrangements() {
+    let mut params = Params::new();
+    params.set("list", "2");
+    params.set("pick", "1");
+    let input = format!("{}\n\nbrwrr\nubwu\n", PATTERNS.join(", "));
+    let parsed = days::get(19).unwrap().parse_with(&input, &params).unwrap();
+    assert_eq!(parsed.part_2(), Ok("2".to_string()));
+    assert_eq!(parsed.explain(1), Ok(None));
+    assert_eq!(
+        parsed.explain(2).unwrap().unwrap(),
+        "brwrr: 2 arrangements\n  b,r,wr,r\n  br,wr,r\n  #1: br,wr,r\nubwu: 0 arrangements\n  #1: none"
+    );
+}
@@ -0,0 +1,78 @@
+use aoc_2024::error::Error;
+use aoc_2024::vm::{self, Machine, Registers, Stop, TraceFormat};
+
+const PROGRAM: [u8; 16] = [2, 4, 1, 2, 7, 5, 4, 5, 0, 3, 1, 7, 5, 5, 3, 0];
+
+#[test]
+fn disassemble_and_assemble_round_trip() {
+    let text = vm::disassemble(&PROGRAM).unwrap();
+    assert_eq!(text.lines().take(4).collect::<Vec<_>>(), ["bst a", "bxl 2", "cdv b", "bxc 5"]);
+    assert_eq!(vm::assemble(&text).unwrap(), PROGRAM);
+}
+
+#[test]
+fn assemble_reports_positions() {
+    assert_eq!(vm::assemble("# counts down\nadv 1\nout a\nbxc\njnz 0\n").unwrap(), [0, 1, 5, 4, 4, 0, 3, 0]);
+    assert_eq!(
+        vm::assemble("adv 1\nmul a"),
+        Err(Error::Parse { line: 2, column: 1, message: "unknown instruction `mul`".to_string() })
+    );
+    assert_eq!(
+        vm::assemble("adv 5"),
+        Err(Error::Parse { line: 1, column: 5, message: "`5` is not a number from 0 to 3 or a register".to_string() })
+    );
+    assert!(vm::assemble("jnz a").is_err());
+    assert!(vm::assemble("out").is_err());
+    assert!(vm::disassemble(&[0, 7]).is_err());
+}
+
+#[test]
+fn machine_stops_at_breakpoints_and_budget() {
+    let mut machine = Machine::new(&PROGRAM, Registers::with_a(22817223));
+    machine.set_breakpoint(12);
+    assert_eq!(machine.run(None), Ok(Stop::Breakpoint(12)));
+    assert!(machine.output().is_empty());
+    assert_eq!(machine.step(), Ok(Some(4)));
+    assert_eq!(machine.run(Some(3)), Ok(Stop::OutOfBudget));
+    assert_eq!(machine.steps(), 10);
+    machine.clear_breakpoint(12);
+    assert_eq!(machine.run(None), Ok(Stop::Halted));
+    assert_eq!(machine.output(), [4, 3, 7, 1, 5, 3, 0, 5, 4]);
+
+    // jumps back to the start forever
+    assert!(vm::run(&[3, 0], Registers::with_a(1), Some(100)).is_err());
+    assert_eq!(vm::run(&[3, 0], Registers::with_a(0), Some(100)), Ok(vec![]));
+}
+
+#[test]
+fn trace_records_each_step() {
+    let code = vm::assemble("adv 1\nout a\njnz 0").unwrap();
+    let mut machine = Machine::new(&code, Registers::with_a(2));
+    machine.start_trace();
+    assert_eq!(machine.run(None), Ok(Stop::Halted));
+    let trace = machine.trace();
+    assert_eq!(trace.len(), 6);
+    assert_eq!(trace[1].output, Some(1));
+    assert_eq!(
+        trace[0].to_json(),
+        r#"{"step":0,"ip":0,"instruction":"adv 1","before":{"a":2,"b":0,"c":0,"ip":0},"after":{"a":1,"b":0,"c":0,"ip":2},"output":null}"#
+    );
+    let table = TraceFormat::Table.render(trace);
+    assert_eq!(table.lines().nth(1), Some("0     0   adv 1        2 -> 1  0  0"));
+    assert_eq!("json".parse(), Ok(TraceFormat::JsonLines));
+}
+
+#[test]
+fn bytes_above_seven_are_errors() {
+    assert!(vm::Instruction::decode(1, 8).is_err());
+    assert!(vm::Instruction::decode(3, 255).is_err());
+    assert!(vm::Instruction::decode(0, 9).is_err());
+    assert!(vm::Instruction::decode(8, 0).is_err());
+    assert!(vm::disassemble(&[1, 8]).is_err());
+
+    let mut machine = Machine::new(&[5, 4, 4, 200], Registers::with_a(3));
+    assert_eq!(machine.step(), Ok(Some(3)));
+    assert!(machine.step().is_err());
+    assert_eq!(machine.steps(), 1);
+    assert!(vm::run(&[1, 8, 3, 0], Registers::with_a(0), Some(10)).is_err());
+}